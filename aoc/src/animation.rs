@@ -0,0 +1,233 @@
+//! Plays a day's [`Simulation`] in the terminal.
+//!
+//! Space pauses and resumes, `.` or the right arrow steps once, `+` and `-` change the speed, `g` followed by a
+//! number and enter jumps to that step, `r` starts over and `q` quits.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use common::Simulation;
+
+/// The slowest and fastest speeds, in steps per second.
+const SPEEDS: (f64, f64) = (0.25, 1000.0);
+
+/// Where an animation is, and what the keys pressed so far asked for.
+pub struct Player<F: Fn() -> Box<dyn Simulation>> {
+    start: F,
+    /// The simulation being played.
+    pub simulation: Box<dyn Simulation>,
+    /// How many steps the simulation has taken.
+    pub step: usize,
+    /// Whether the simulation ran out of steps.
+    pub finished: bool,
+    /// Whether the space bar stopped the animation.
+    pub paused: bool,
+    /// Steps per second.
+    pub speed: f64,
+    /// The step number being typed after `g`.
+    pub target: Option<String>,
+}
+
+impl<F: Fn() -> Box<dyn Simulation>> Player<F> {
+    /// A player running the simulation `start` makes at `speed` steps per second.
+    pub fn new(start: F, speed: f64) -> Player<F> {
+        let simulation = start();
+        return Player { start, simulation, step: 0, finished: false, paused: false, speed, target: None };
+    }
+
+    /// Takes a step, or notes that the simulation has finished.
+    pub fn advance(&mut self) {
+        if !self.finished && self.simulation.step() {
+            self.step += 1;
+        } else {
+            self.finished = true;
+        }
+    }
+
+    /// Gets to `step`, starting over if it has already been passed.
+    pub fn jump(&mut self, step: usize) {
+        if step < self.step {
+            self.simulation = (self.start)();
+            self.step = 0;
+            self.finished = false;
+        }
+        while self.step < step && !self.finished {
+            self.advance();
+        }
+    }
+
+    /// Acts on a key, and returns `false` once the player should stop.
+    pub fn press(&mut self, key: KeyCode) -> bool {
+        if let Some(target) = &mut self.target {
+            match key {
+                KeyCode::Char(digit) if digit.is_ascii_digit() => target.push(digit),
+                KeyCode::Backspace => {
+                    target.pop();
+                }
+                KeyCode::Enter => {
+                    let step = target.parse().unwrap_or(self.step);
+                    self.target = None;
+                    self.paused = true;
+                    self.jump(step);
+                }
+                KeyCode::Esc => self.target = None,
+                _ => {}
+            }
+            return true;
+        }
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('.') | KeyCode::Right => {
+                self.paused = true;
+                self.advance();
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => self.speed = (self.speed * 2.0).min(SPEEDS.1),
+            KeyCode::Char('-') => self.speed = (self.speed / 2.0).max(SPEEDS.0),
+            KeyCode::Char('g') => self.target = Some(String::new()),
+            KeyCode::Char('r') => self.jump(0),
+            _ => {}
+        }
+        return true;
+    }
+
+    fn status(&self) -> String {
+        let state = if self.finished { "finished" } else if self.paused { "paused" } else { "playing" };
+        let controls = match &self.target {
+            Some(target) => format!("Go to step: {target}_"),
+            None => "space pause  . step  +/- speed  g go to step  r restart  q quit".to_string(),
+        };
+        return format!("Step {} ({state}, {} steps/s)  {}\n{controls}", self.step, self.speed, self.simulation.status());
+    }
+}
+
+/// Runs the animation until `q` is pressed, restoring the terminal afterwards.
+pub fn play(start: impl Fn() -> Box<dyn Simulation>, speed: f64, first_step: usize) -> io::Result<()> {
+    let mut player = Player::new(start, speed.clamp(SPEEDS.0, SPEEDS.1));
+    player.jump(first_step);
+
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    let result = run(&mut player, &mut stdout);
+    execute!(stdout, Show, LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    return result;
+}
+
+fn run<F: Fn() -> Box<dyn Simulation>>(player: &mut Player<F>, stdout: &mut impl Write) -> io::Result<()> {
+    let mut next_step = Instant::now();
+    loop {
+        draw(player, stdout)?;
+        let timeout = if player.paused || player.finished {
+            Duration::from_millis(250)
+        } else {
+            next_step.saturating_duration_since(Instant::now())
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                let interrupted = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                if key.kind == KeyEventKind::Press && (interrupted || !player.press(key.code)) {
+                    return Ok(());
+                }
+            }
+        } else if !player.paused && !player.finished {
+            player.advance();
+            next_step = Instant::now() + Duration::from_secs_f64(1.0 / player.speed);
+        }
+    }
+}
+
+/// Draws the frame cut down to the terminal, with the status below it.
+fn draw<F: Fn() -> Box<dyn Simulation>>(player: &Player<F>, stdout: &mut impl Write) -> io::Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let frame = player.simulation.frame();
+    let status = player.status();
+    queue!(stdout, MoveTo(0, 0), Clear(ClearType::All))?;
+    let frame_rows = (rows as usize).saturating_sub(status.lines().count() + 1);
+    for line in frame.lines().take(frame_rows).chain([""]).chain(status.lines()) {
+        let line: String = line.chars().take(columns as usize).collect();
+        write!(stdout, "{line}\r\n")?;
+    }
+    return stdout.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to a limit.
+    struct Counter {
+        count: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.count == self.limit {
+                return false;
+            }
+            self.count += 1;
+            return true;
+        }
+
+        fn frame(&self) -> String {
+            return self.count.to_string();
+        }
+
+        fn status(&self) -> String {
+            return String::new();
+        }
+    }
+
+    fn player() -> Player<impl Fn() -> Box<dyn Simulation>> {
+        return Player::new(|| Box::new(Counter { count: 0, limit: 10 }) as Box<dyn Simulation>, 10.0);
+    }
+
+    #[test]
+    fn steps_once_and_pauses() {
+        let mut player = player();
+        assert!(player.press(KeyCode::Char('.')));
+        assert!(player.press(KeyCode::Right));
+        assert_eq!(player.step, 2);
+        assert!(player.paused);
+        assert_eq!(player.simulation.frame(), "2");
+    }
+
+    #[test]
+    fn jumps_forwards_and_backwards() {
+        let mut player = player();
+        for key in [KeyCode::Char('g'), KeyCode::Char('7'), KeyCode::Enter] {
+            player.press(key);
+        }
+        assert_eq!((player.step, player.simulation.frame()), (7, "7".to_string()));
+        for key in [KeyCode::Char('g'), KeyCode::Char('3'), KeyCode::Enter] {
+            player.press(key);
+        }
+        assert_eq!((player.step, player.simulation.frame()), (3, "3".to_string()));
+    }
+
+    #[test]
+    fn stops_at_the_end() {
+        let mut player = player();
+        player.jump(50);
+        assert_eq!(player.step, 10);
+        assert!(player.finished);
+        player.press(KeyCode::Char('r'));
+        assert_eq!((player.step, player.finished), (0, false));
+    }
+
+    #[test]
+    fn quits_and_changes_speed() {
+        let mut player = player();
+        player.press(KeyCode::Char('+'));
+        assert_eq!(player.speed, 20.0);
+        player.press(KeyCode::Char('-'));
+        player.press(KeyCode::Char('-'));
+        assert_eq!(player.speed, 5.0);
+        assert!(!player.press(KeyCode::Char('q')));
+    }
+}
@@ -0,0 +1,110 @@
+//! The accepted answers stored for each day's inputs, which `verify` checks the solutions against.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+/// The accepted answers for one input, stored as `answers/dayNN/<input-name>.toml`.
+///
+/// The input itself is expected at `dayNN/<input-name>.txt`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Answers {
+    /// The answer to part 1.
+    pub part1: String,
+    /// The answer to part 2.
+    pub part2: String,
+}
+
+impl Answers {
+    /// Reads the answers stored at `path`.
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        return toml::from_str(&fs::read_to_string(path)?).map_err(io::Error::other);
+    }
+
+    /// Stores the answers at `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        return fs::write(path, toml::to_string(self).map_err(io::Error::other)?);
+    }
+
+    /// A line diff of every part whose answer differs from `actual`, or `None` when they all match.
+    pub fn diff(&self, actual: &[String; 2]) -> Option<String> {
+        let mut diff = String::new();
+        for (part, (expected, actual)) in [&self.part1, &self.part2].into_iter().zip(actual).enumerate() {
+            if expected == actual {
+                continue;
+            }
+            diff.push_str(&format!("  part{}:\n", part + 1));
+            for line in expected.lines() {
+                diff.push_str(&format!("    - {line}\n"));
+            }
+            for line in actual.lines() {
+                diff.push_str(&format!("    + {line}\n"));
+            }
+        }
+        return if diff.is_empty() { None } else { Some(diff) };
+    }
+}
+
+/// Where the answers for `day`'s input `name` are stored.
+pub fn answers_path(day: u8, name: &str) -> PathBuf {
+    return PathBuf::from(format!("answers/day{day:02}/{name}.toml"));
+}
+
+/// Where `day`'s input `name` is stored.
+pub fn input_path(day: u8, name: &str) -> PathBuf {
+    return PathBuf::from(format!("day{day:02}/{name}.txt"));
+}
+
+/// The names of every input with stored answers for `day`, sorted.
+pub fn stored_inputs(day: u8) -> io::Result<Vec<String>> {
+    let directory = PathBuf::from(format!("answers/day{day:02}"));
+    let entries = match fs::read_dir(&directory) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let mut names = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "toml") {
+            names.extend(path.file_stem().and_then(|stem| stem.to_str()).map(str::to_owned));
+        }
+    }
+    names.sort();
+    return Ok(names);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(part1: &str, part2: &str) -> Answers {
+        return Answers { part1: part1.to_string(), part2: part2.to_string() };
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let stored = answers("6440", "5905");
+        let text = toml::to_string(&stored).unwrap();
+        assert_eq!(text, "part1 = \"6440\"\npart2 = \"5905\"\n");
+        assert_eq!(toml::from_str::<Answers>(&text).unwrap(), stored);
+    }
+
+    #[test]
+    fn diffs_only_the_parts_that_changed() {
+        let stored = answers("6440", "5905");
+        assert_eq!(stored.diff(&["6440".to_string(), "5905".to_string()]), None);
+        assert_eq!(stored.diff(&["6440".to_string(), "5906".to_string()]).unwrap(),
+                   "  part2:\n    - 5905\n    + 5906\n");
+    }
+
+    #[test]
+    fn locates_inputs_and_answers() {
+        assert_eq!(answers_path(7, "example"), PathBuf::from("answers/day07/example.toml"));
+        assert_eq!(input_path(12, "input"), PathBuf::from("day12/input.txt"));
+    }
+}
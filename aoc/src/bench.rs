@@ -0,0 +1,122 @@
+//! Timing statistics, and the baseline `bench` compares them with to spot regressions.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Summary of repeated timings of one phase. Times are in nanoseconds.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Stats {
+    /// How many timings were taken.
+    pub runs: usize,
+    /// The average timing.
+    pub mean: f64,
+    /// The middle timing.
+    pub median: f64,
+    /// How far the timings spread around the mean, squared.
+    pub variance: f64,
+}
+
+impl Stats {
+    /// The summary of `samples`, which must not be empty.
+    pub fn of(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "cannot summarise zero timings");
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+        let runs = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let median = if runs % 2 == 1 {
+            nanos[runs / 2]
+        } else {
+            (nanos[runs / 2 - 1] + nanos[runs / 2]) / 2.0
+        };
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+        return Stats { runs, mean, median, variance };
+    }
+
+    /// The standard deviation of the timings.
+    pub fn std_dev(&self) -> f64 {
+        return self.variance.sqrt();
+    }
+
+    /// How much slower this is than `baseline` as a fraction, if the medians differ by more than `threshold`.
+    pub fn regression(&self, baseline: &Stats, threshold: f64) -> Option<f64> {
+        let slowdown = self.median / baseline.median - 1.0;
+        return if slowdown > threshold { Some(slowdown) } else { None };
+    }
+}
+
+/// Saved timings, keyed by day and input, then by phase.
+pub type Baseline = BTreeMap<String, BTreeMap<String, Stats>>;
+
+/// The key a day's timings on `input` are saved under.
+pub fn baseline_key(day: u8, input: &str) -> String {
+    return format!("day{day:02} {input}");
+}
+
+/// Reads a baseline, or starts an empty one when none was saved yet.
+pub fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    return match fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json).map_err(io::Error::other),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(e) => Err(e),
+    };
+}
+
+/// Writes `baseline` as JSON, replacing what was saved before.
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(baseline).map_err(io::Error::other)?;
+    return fs::write(path, json + "\n");
+}
+
+/// Renders a time in nanoseconds with a unit that keeps it readable.
+pub fn format_nanos(nanos: f64) -> String {
+    return if nanos < 1e3 {
+        format!("{nanos:.0}ns")
+    } else if nanos < 1e6 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        return values.iter().map(|ms| Duration::from_millis(*ms)).collect();
+    }
+
+    #[test]
+    fn summarises_timings() {
+        let stats = Stats::of(&millis(&[4, 1, 3, 2]));
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.mean, 2.5e6);
+        assert_eq!(stats.median, 2.5e6);
+        assert_eq!(stats.variance, 1.25e12);
+
+        assert_eq!(Stats::of(&millis(&[5, 1, 100])).median, 5e6);
+    }
+
+    #[test]
+    fn flags_slowdowns_past_the_threshold() {
+        let baseline = Stats::of(&millis(&[10]));
+        assert_eq!(Stats::of(&millis(&[11])).regression(&baseline, 0.2), None);
+        assert_eq!(Stats::of(&millis(&[15])).regression(&baseline, 0.2), Some(0.5));
+        assert_eq!(Stats::of(&millis(&[5])).regression(&baseline, 0.2), None);
+    }
+
+    #[test]
+    fn formats_times() {
+        assert_eq!(format_nanos(512.0), "512ns");
+        assert_eq!(format_nanos(1_500.0), "1.50µs");
+        assert_eq!(format_nanos(2_345_000.0), "2.35ms");
+        assert_eq!(format_nanos(3e9), "3.00s");
+    }
+}
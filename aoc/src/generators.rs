@@ -0,0 +1,70 @@
+//! Random puzzle inputs, for stress testing and benchmarking the solutions.
+//!
+//! Every day has a `generate` function turning a random number generator and a size into an input the
+//! puzzle could have given, which its solution can solve. What the size counts differs per day, and sizes too
+//! small for a day's puzzle are raised to the smallest it can have.
+
+use std::collections::HashSet;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::Day;
+
+pub(crate) mod day01;
+pub(crate) mod day02;
+pub(crate) mod day03;
+pub(crate) mod day04;
+pub(crate) mod day05;
+pub(crate) mod day06;
+pub(crate) mod day07;
+pub(crate) mod day08;
+pub(crate) mod day09;
+pub(crate) mod day10;
+pub(crate) mod day11;
+pub(crate) mod day12;
+pub(crate) mod day13;
+pub(crate) mod day14;
+pub(crate) mod day15;
+pub(crate) mod day16;
+pub(crate) mod day17;
+pub(crate) mod day18;
+pub(crate) mod day19;
+pub(crate) mod day20;
+
+/// A day's input generator.
+pub struct Generator {
+    /// Makes an input of the given size from the random number generator.
+    pub generate: fn(&mut StdRng, usize) -> String,
+    /// A size that gives about as much input as the puzzle did.
+    pub size: usize,
+}
+
+/// The same seed and size always give the same input.
+pub fn generate(day: &Day, seed: u64, size: usize) -> String {
+    return (day.generator.generate)(&mut StdRng::seed_from_u64(seed), size);
+}
+
+/// Lays out `width` by `height` cells from `cell`, row by row.
+fn grid(rng: &mut StdRng, width: usize, height: usize, mut cell: impl FnMut(&mut StdRng) -> char) -> String {
+    let mut input = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        for _ in 0..width {
+            input.push(cell(rng));
+        }
+        input.push('\n');
+    }
+    return input;
+}
+
+/// `count` different names of `length` characters from `alphabet`, none of which is in `reserved`.
+fn names(rng: &mut StdRng, count: usize, length: usize, alphabet: &str, reserved: &[&str]) -> Vec<String> {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    let mut taken: HashSet<String> = reserved.iter().map(|name| name.to_string()).collect();
+    let mut names = vec![];
+    while names.len() < count {
+        let name: String = (0..length).map(|_| alphabet[rng.gen_range(0..alphabet.len())]).collect();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    return names;
+}
@@ -0,0 +1,27 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+pub const GENERATOR: Generator = Generator { generate, size: 1000 };
+
+/// `size` lines of letters, digits and spelled out digits, each with at least one real digit.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.gen_range(1..8) {
+            match rng.gen_range(0..3) {
+                0 => line.push(char::from(b'a' + rng.gen_range(0..26))),
+                1 => line.push(char::from(b'1' + rng.gen_range(0..9))),
+                _ => line.push_str(WORDS[rng.gen_range(0..WORDS.len())]),
+            }
+        }
+        let digit = char::from(b'1' + rng.gen_range(0..9));
+        line.insert(rng.gen_range(0..=line.len()), digit);
+        input.push_str(&line);
+        input.push('\n');
+    }
+    return input;
+}
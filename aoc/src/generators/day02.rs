@@ -0,0 +1,25 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use super::Generator;
+
+pub const GENERATOR: Generator = Generator { generate, size: 100 };
+
+/// `size` games of up to six draws, each showing up to 20 cubes of some of the three colours.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let mut draws = vec![];
+        for _ in 0..rng.gen_range(1..=6) {
+            let mut colours = ["red", "green", "blue"];
+            colours.shuffle(rng);
+            let cubes: Vec<String> = colours[..rng.gen_range(1..=3)]
+                .iter()
+                .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+                .collect();
+            draws.push(cubes.join(", "));
+        }
+        input.push_str(&format!("Game {id}: {}\n", draws.join("; ")));
+    }
+    return input;
+}
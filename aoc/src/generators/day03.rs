@@ -0,0 +1,35 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+pub const GENERATOR: Generator = Generator { generate, size: 140 };
+
+/// A `size` by `size` schematic of numbers of up to three digits, with symbols scattered between them.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < size {
+            let digits: u32 = rng.gen_range(1..=3);
+            if rng.gen_bool(0.3) && row.len() + digits as usize <= size {
+                row.push_str(&rng.gen_range(10usize.pow(digits - 1)..10usize.pow(digits)).to_string());
+                // Something other than a digit must follow, so the next number does not run into this one
+                if row.len() < size {
+                    row.push(gap(rng));
+                }
+            } else {
+                row.push(gap(rng));
+            }
+        }
+        input.push_str(&row);
+        input.push('\n');
+    }
+    return input;
+}
+
+fn gap(rng: &mut StdRng) -> char {
+    return if rng.gen_bool(0.15) { char::from(SYMBOLS[rng.gen_range(0..SYMBOLS.len())]) } else { '.' };
+}
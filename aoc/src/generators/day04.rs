@@ -0,0 +1,26 @@
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use super::Generator;
+
+pub const GENERATOR: Generator = Generator { generate, size: 200 };
+
+/// `size` cards of ten winning numbers and 25 numbers you have, from 1 to 99.
+///
+/// Most cards win nothing and the rest only a few, so the number of copies stays manageable, and
+/// no card wins copies of cards past the end of the table.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for number in 1..=size {
+        let matches = if rng.gen_bool(0.7) { 0 } else { rng.gen_range(1..=4) }.min(size - number);
+        let mut numbers: Vec<usize> = index::sample(rng, 99, 35 - matches).into_iter().map(|n| n + 1).collect();
+        let winning: Vec<usize> = numbers[..10].to_vec();
+        let mut actual: Vec<usize> = numbers.split_off(10);
+        actual.extend(&winning[..matches]);
+        actual.shuffle(rng);
+        let winning: Vec<String> = winning.iter().map(|n| format!("{n:>2}")).collect();
+        let actual: Vec<String> = actual.iter().map(|n| format!("{n:>2}")).collect();
+        input.push_str(&format!("Card {number:>3}: {} | {}\n", winning.join(" "), actual.join(" ")));
+    }
+    return input;
+}
@@ -0,0 +1,42 @@
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use super::Generator;
+
+const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+pub const GENERATOR: Generator = Generator { generate, size: 30 };
+
+/// An almanac whose maps each cut the numbers below `size * 1000` into `size` pieces and shuffle them.
+///
+/// Every map is a permutation, as in the puzzle.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let limit = size * 1000;
+    let mut seeds = vec![];
+    for _ in 0..5 {
+        let start = rng.gen_range(0..limit);
+        seeds.push(format!("{start} {}", rng.gen_range(1..=limit / 10)));
+    }
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for categories in CATEGORIES.windows(2) {
+        let mut bounds: Vec<usize> = index::sample(rng, limit - 1, size - 1).into_iter().map(|cut| cut + 1).collect();
+        bounds.push(0);
+        bounds.push(limit);
+        bounds.sort();
+        let mut pieces: Vec<(usize, usize)> = bounds.windows(2).map(|pair| (pair[0], pair[1] - pair[0])).collect();
+        pieces.shuffle(rng);
+
+        input.push_str(&format!("\n{}-to-{} map:\n", categories[0], categories[1]));
+        let mut destination = 0;
+        let mut lines = vec![];
+        for (source, length) in pieces {
+            lines.push(format!("{destination} {source} {length}\n"));
+            destination += length;
+        }
+        lines.shuffle(rng);
+        input.push_str(&lines.concat());
+    }
+    return input;
+}
@@ -0,0 +1,21 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+pub const GENERATOR: Generator = Generator { generate, size: 4 };
+
+/// `size` races, at most four, since part 2 reads all their digits as a single race.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut times = vec![];
+    let mut records = vec![];
+    for _ in 0..size.clamp(1, 4) {
+        let time: u64 = rng.gen_range(10..100);
+        let best = (time / 2) * (time - time / 2);
+        times.push(time);
+        records.push(rng.gen_range(best / 2..best));
+    }
+    let width = records.iter().map(|record| record.to_string().len()).max().unwrap() + 2;
+    let times: String = times.iter().map(|time| format!("{time:>width$}")).collect();
+    let records: String = records.iter().map(|record| format!("{record:>width$}")).collect();
+    return format!("Time:    {times}\nDistance:{records}\n");
+}
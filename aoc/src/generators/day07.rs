@@ -0,0 +1,21 @@
+use std::collections::HashSet;
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+pub const GENERATOR: Generator = Generator { generate, size: 1000 };
+
+/// `size` different hands, at most as many as there are, with bids up to 1000.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+    while hands.len() < size.min(CARDS.len().pow(5)) {
+        let hand: String = (0..5).map(|_| char::from(CARDS[rng.gen_range(0..CARDS.len())])).collect();
+        if hands.insert(hand.clone()) {
+            input.push_str(&format!("{hand} {}\n", rng.gen_range(1..=1000)));
+        }
+    }
+    return input;
+}
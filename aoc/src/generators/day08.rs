@@ -0,0 +1,48 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use super::{names, Generator};
+
+const INSTRUCTION_COUNTS: [usize; 7] = [263, 269, 271, 277, 281, 283, 293];
+const LAPS: [usize; 10] = [41, 43, 47, 53, 59, 61, 67, 71, 73, 79];
+const INNER: &str = "BCDEFGHIJKLMNOPQRSTUVWXY";
+
+pub const GENERATOR: Generator = Generator { generate, size: 6 };
+
+/// A network for `size` ghosts, at most ten, one of which walks from `AAA` to `ZZZ`.
+///
+/// As in the puzzle, each ghost walks a loop through its own nodes that takes a prime number of laps of
+/// the instructions, and its `Z` node leads to the same place as its `A` node.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let ghosts = size.clamp(1, LAPS.len());
+    let instruction_count = *INSTRUCTION_COUNTS.choose(rng).unwrap();
+    let instructions: String = (0..instruction_count).map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' }).collect();
+    let laps: Vec<usize> = LAPS.choose_multiple(rng, ghosts).copied().collect();
+
+    // Leaving out `A` and `Z` keeps the nodes along the way from looking like starts or ends
+    let inner_count: usize = laps.iter().map(|lap| lap * instruction_count - 1).sum();
+    let length = (3..).find(|length| INNER.len().pow(*length) >= 2 * inner_count).unwrap() as usize;
+    let mut inner = names(rng, inner_count, length, INNER, &[]);
+    let ends = names(rng, ghosts * 2 - 2, 2, INNER, &[]);
+
+    let mut lines = vec![];
+    for (ghost, lap) in laps.iter().enumerate() {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (format!("{}A", ends[2 * ghost - 2]), format!("{}Z", ends[2 * ghost - 1]))
+        };
+        let steps: Vec<String> = inner.drain(..lap * instruction_count - 1).collect();
+        let mut path = vec![start];
+        path.extend(steps);
+        path.push(end.clone());
+        for (i, node) in path.iter().enumerate() {
+            let next = if i + 1 < path.len() { &path[i + 1] } else { &path[1] };
+            let other = path.choose(rng).unwrap();
+            let (left, right) = if instructions.as_bytes()[i % instruction_count] == b'L' { (next, other) } else { (other, next) };
+            lines.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+    lines.shuffle(rng);
+    return format!("{instructions}\n\n{}\n", lines.join("\n"));
+}
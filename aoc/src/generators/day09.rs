@@ -0,0 +1,19 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+pub const GENERATOR: Generator = Generator { generate, size: 200 };
+
+/// `size` sequences of 21 values of polynomials of degree up to six, as the puzzle's always are.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..=rng.gen_range(0..=6)).map(|_| rng.gen_range(-6..=6)).collect();
+        let values: Vec<String> = (0..21i64)
+            .map(|x| coefficients.iter().rev().fold(0, |value, coefficient| value * x + coefficient).to_string())
+            .collect();
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+    return input;
+}
@@ -0,0 +1,140 @@
+use std::collections::{HashSet, VecDeque};
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+const PIPES: &[u8] = b"|-LJ7F";
+
+pub const GENERATOR: Generator = Generator { generate, size: 140 };
+
+/// A `size` by `size` field with one big loop of pipe, and junk pipes everywhere else.
+///
+/// The loop runs around a random blob of blocks, each two tiles wide. Blocks never touch only at a
+/// corner and the blob has no holes, so its outline is a single loop that never touches itself.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5);
+    let blocks = (size - 1) / 2;
+    let blob = blob(rng, blocks);
+
+    // The outline, as edges between the corners of the blocks
+    let mut edges: Vec<((usize, usize), (usize, usize))> = vec![];
+    for &(x, y) in &blob {
+        let outside = |dx: i64, dy: i64| !blob.contains(&((x as i64 + dx) as usize, (y as i64 + dy) as usize))
+            || (x == 0 && dx < 0) || (y == 0 && dy < 0);
+        if outside(0, -1) { edges.push(((x, y), (x + 1, y))); }
+        if outside(0, 1) { edges.push(((x, y + 1), (x + 1, y + 1))); }
+        if outside(-1, 0) { edges.push(((x, y), (x, y + 1))); }
+        if outside(1, 0) { edges.push(((x + 1, y), (x + 1, y + 1))); }
+    }
+
+    let mut tiles = vec![vec!['.'; size]; size];
+    let mut on_loop = HashSet::new();
+    for ((x1, y1), (x2, y2)) in edges {
+        let (x1, y1, x2, y2) = (x1 * 2, y1 * 2, x2 * 2, y2 * 2);
+        tiles[(y1 + y2) / 2][(x1 + x2) / 2] = if y1 == y2 { '-' } else { '|' };
+        on_loop.extend([(x1, y1), ((x1 + x2) / 2, (y1 + y2) / 2), (x2, y2)]);
+    }
+    for &(x, y) in &on_loop {
+        if x % 2 == 0 && y % 2 == 0 {
+            let up = y > 0 && on_loop.contains(&(x, y - 1));
+            let down = on_loop.contains(&(x, y + 1));
+            let left = x > 0 && on_loop.contains(&(x - 1, y));
+            let right = on_loop.contains(&(x + 1, y));
+            tiles[y][x] = match (up, down, left, right) {
+                (true, true, _, _) => '|',
+                (_, _, true, true) => '-',
+                (true, _, true, _) => 'J',
+                (true, _, _, true) => 'L',
+                (_, true, true, _) => '7',
+                _ => 'F',
+            };
+        }
+    }
+
+    for y in 0..size {
+        for x in 0..size {
+            if !on_loop.contains(&(x, y)) && rng.gen_bool(0.6) {
+                tiles[y][x] = char::from(PIPES[rng.gen_range(0..PIPES.len())]);
+            }
+        }
+    }
+
+    let mut loop_tiles: Vec<_> = on_loop.into_iter().collect();
+    loop_tiles.sort();
+    let (start_x, start_y) = loop_tiles[rng.gen_range(0..loop_tiles.len())];
+    tiles[start_y][start_x] = 'S';
+    // Junk next to the start must not look connected to it
+    let junk_pointing_in = [(0, -1, "|7F"), (0, 1, "|LJ"), (-1, 0, "-LF"), (1, 0, "-J7")];
+    for (dx, dy, pipes) in junk_pointing_in {
+        let (x, y) = ((start_x as i64 + dx) as usize, (start_y as i64 + dy) as usize);
+        if y < size && x < size && !loop_tiles.contains(&(x, y)) && pipes.contains(tiles[y][x]) {
+            tiles[y][x] = '.';
+        }
+    }
+
+    return tiles.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+}
+
+/// A random set of connected blocks in a `size` by `size` area, with no holes and no two blocks
+/// touching only at a corner.
+fn blob(rng: &mut StdRng, size: usize) -> HashSet<(usize, usize)> {
+    let mut blob = HashSet::from([(rng.gen_range(0..size), rng.gen_range(0..size))]);
+    let target = (size * size / 2).max(1);
+    let mut attempts = 0;
+    while blob.len() < target && attempts < size * size * 20 {
+        attempts += 1;
+        let candidate = (rng.gen_range(0..size), rng.gen_range(0..size));
+        if blob.contains(&candidate) || !neighbours(candidate, size).iter().any(|n| blob.contains(n)) {
+            continue;
+        }
+        blob.insert(candidate);
+        if pinched(&blob, candidate) {
+            blob.remove(&candidate);
+        }
+    }
+
+    // Whatever the outside cannot reach is a hole, so it becomes part of the blob
+    let mut outside = HashSet::new();
+    let mut queue: VecDeque<(usize, usize)> = (0..size)
+        .flat_map(|i| [(i, 0), (i, size - 1), (0, i), (size - 1, i)])
+        .filter(|block| !blob.contains(block))
+        .collect();
+    outside.extend(queue.iter().copied());
+    while let Some(block) = queue.pop_front() {
+        for neighbour in neighbours(block, size) {
+            if !blob.contains(&neighbour) && outside.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    for x in 0..size {
+        for y in 0..size {
+            if !outside.contains(&(x, y)) {
+                blob.insert((x, y));
+            }
+        }
+    }
+    return blob;
+}
+
+fn neighbours((x, y): (usize, usize), size: usize) -> Vec<(usize, usize)> {
+    let mut neighbours = vec![];
+    if x > 0 { neighbours.push((x - 1, y)); }
+    if y > 0 { neighbours.push((x, y - 1)); }
+    if x + 1 < size { neighbours.push((x + 1, y)); }
+    if y + 1 < size { neighbours.push((x, y + 1)); }
+    return neighbours;
+}
+
+/// Whether any two-by-two square around `block` has blocks only on one of its diagonals.
+fn pinched(blob: &HashSet<(usize, usize)>, (x, y): (usize, usize)) -> bool {
+    let contains = |x: i64, y: i64| x >= 0 && y >= 0 && blob.contains(&(x as usize, y as usize));
+    let (x, y) = (x as i64, y as i64);
+    for (left, top) in [(x - 1, y - 1), (x, y - 1), (x - 1, y), (x, y)] {
+        let square = [contains(left, top), contains(left + 1, top), contains(left, top + 1), contains(left + 1, top + 1)];
+        if square == [true, false, false, true] || square == [false, true, true, false] {
+            return true;
+        }
+    }
+    return false;
+}
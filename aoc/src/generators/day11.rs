@@ -0,0 +1,24 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+pub const GENERATOR: Generator = Generator { generate, size: 140 };
+
+/// A `size` by `size` image with a galaxy on about one pixel in twenty, and some empty rows and columns.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(2);
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let mut image = vec![vec!['.'; size]; size];
+    for (y, row) in image.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            if !empty_rows[y] && !empty_columns[x] && rng.gen_bool(0.05) {
+                *pixel = '#';
+            }
+        }
+    }
+    // At least two galaxies, so there is a distance to measure
+    image[0][0] = '#';
+    image[size - 1][size - 1] = '#';
+    return image.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+}
@@ -0,0 +1,20 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+pub const GENERATOR: Generator = Generator { generate, size: 1000 };
+
+/// `size` rows of up to 20 springs, each with at least one arrangement and about two in five unknown.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut springs: Vec<char> = (0..rng.gen_range(1..=20)).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }).collect();
+        let damaged = rng.gen_range(0..springs.len());
+        springs[damaged] = '#';
+        let filled: String = springs.iter().collect();
+        let counts: Vec<String> = filled.split('.').filter(|group| !group.is_empty()).map(|group| group.len().to_string()).collect();
+        let line: String = springs.iter().map(|spring| if rng.gen_bool(0.4) { '?' } else { *spring }).collect();
+        input.push_str(&format!("{line} {}\n", counts.join(",")));
+    }
+    return input;
+}
@@ -0,0 +1,81 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::Generator;
+
+pub const GENERATOR: Generator = Generator { generate, size: 100 };
+
+/// `size` patterns, each with exactly one line of reflection, and exactly one other line that a single
+/// smudge spoils.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size.max(1)).map(|_| pattern(rng)).collect();
+    return patterns.join("\n");
+}
+
+fn pattern(rng: &mut StdRng) -> String {
+    loop {
+        let width = rng.gen_range(5..=17);
+        let height = rng.gen_range(5..=17);
+        let reflection = rng.gen_range(1..width);
+        let smudged = rng.gen_range(1..width);
+        // The smudge has to be outside what the real reflection mirrors, and inside what the other one does
+        let smudge_columns: Vec<usize> = (0..width)
+            .filter(|column| mirror(*column, reflection, width).is_none() && mirror(*column, smudged, width).is_some())
+            .collect();
+        if smudge_columns.is_empty() {
+            continue;
+        }
+
+        // Columns that either line maps onto each other must match
+        let mut classes: Vec<usize> = (0..width).collect();
+        for line in [reflection, smudged] {
+            for column in 0..width {
+                if let Some(other) = mirror(column, line, width) {
+                    let (a, b) = (find(&mut classes, column), find(&mut classes, other));
+                    classes[a] = b;
+                }
+            }
+        }
+        let mut rows: Vec<Vec<bool>> = vec![];
+        for _ in 0..height {
+            let values: Vec<bool> = (0..width).map(|_| rng.gen_bool(0.5)).collect();
+            rows.push((0..width).map(|column| values[find(&mut classes, column)]).collect());
+        }
+        let row = rng.gen_range(0..height);
+        let column = smudge_columns[rng.gen_range(0..smudge_columns.len())];
+        rows[row][column] = !rows[row][column];
+
+        // Nothing else may happen to reflect, with or without a smudge
+        let transposed: Vec<Vec<bool>> = (0..width).map(|x| rows.iter().map(|row| row[x]).collect()).collect();
+        let mut lines: Vec<usize> = (1..width).map(|line| differences(&rows, line)).collect();
+        lines.extend((1..height).map(|line| differences(&transposed, line)));
+        if lines.iter().filter(|d| **d == 0).count() != 1 || lines.iter().filter(|d| **d == 1).count() != 1 {
+            continue;
+        }
+
+        let rows = if rng.gen_bool(0.5) { transposed } else { rows };
+        return rows.iter()
+            .map(|row| row.iter().map(|rock| if *rock { '#' } else { '.' }).collect::<String>() + "\n")
+            .collect();
+    }
+}
+
+/// The column `column` is reflected onto by a line left of column `line`, if it is within the pattern.
+fn mirror(column: usize, line: usize, width: usize) -> Option<usize> {
+    return (2 * line).checked_sub(column + 1).filter(|other| *other < width);
+}
+
+fn find(classes: &mut [usize], column: usize) -> usize {
+    let mut root = column;
+    while classes[root] != root {
+        root = classes[root];
+    }
+    classes[column] = root;
+    return root;
+}
+
+/// How many characters differ across a line left of column `line`.
+fn differences(rows: &[Vec<bool>], line: usize) -> usize {
+    return rows.iter()
+        .map(|row| row[..line].iter().rev().zip(&row[line..]).filter(|(a, b)| a != b).count())
+        .sum();
+}
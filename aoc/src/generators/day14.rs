@@ -0,0 +1,15 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::{grid, Generator};
+
+pub const GENERATOR: Generator = Generator { generate, size: 100 };
+
+/// A `size` by `size` platform, with rounded rocks on a fifth of it and cube rocks on a sixth.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    return grid(rng, size, size, |rng| match rng.gen_range(0..30) {
+        0..=5 => 'O',
+        6..=10 => '#',
+        _ => '.',
+    });
+}
@@ -0,0 +1,22 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::{names, Generator};
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+pub const GENERATOR: Generator = Generator { generate, size: 4000 };
+
+/// An initialization sequence of `size` steps, on a few labels that come up over and over.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut labels = vec![];
+    for length in 2..=6 {
+        labels.extend(names(rng, size / 20 + 1, length, LETTERS, &[]));
+    }
+    let steps: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) { format!("{label}-") } else { format!("{label}={}", rng.gen_range(1..=9)) }
+        })
+        .collect();
+    return steps.join(",") + "\n";
+}
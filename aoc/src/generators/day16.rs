@@ -0,0 +1,15 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::{grid, Generator};
+
+const DEVICES: &[u8] = b"/\\|-";
+
+pub const GENERATOR: Generator = Generator { generate, size: 110 };
+
+/// A `size` by `size` contraption, with a mirror or splitter on about one tile in ten.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    return grid(rng, size, size, |rng| {
+        if rng.gen_bool(0.1) { char::from(DEVICES[rng.gen_range(0..DEVICES.len())]) } else { '.' }
+    });
+}
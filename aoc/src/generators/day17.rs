@@ -0,0 +1,13 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use super::{grid, Generator};
+
+pub const GENERATOR: Generator = Generator { generate, size: 141 };
+
+/// A `size` by `size` map of heat losses from 1 to 9.
+///
+/// It is at least 5 by 5, as the ultra crucible must move 4 blocks in a straight line before it can turn or stop.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5);
+    return grid(rng, size, size, |rng| char::from(b'1' + rng.gen_range(0..9)));
+}
@@ -0,0 +1,62 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use common::Coord;
+use super::Generator;
+
+const DIRECTIONS: [(i64, i64, char); 4] = [(1, 0, 'R'), (0, 1, 'D'), (-1, 0, 'L'), (0, -1, 'U')];
+
+pub const GENERATOR: Generator = Generator { generate, size: 170 };
+
+/// A dig plan around `size` columns, with a much larger plan around as many columns hidden in the colours.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let columns = size.max(1);
+    let plan = staircase(rng, columns, 6, 10);
+    let hidden = staircase(rng, columns, 0x3FFFF, 0x7FFFF);
+
+    let mut input = String::new();
+    for (path, hidden) in plan.iter().zip(&hidden) {
+        let letter = DIRECTIONS[direction(*path)].2;
+        let length = hidden.x.abs() + hidden.y.abs();
+        input.push_str(&format!("{letter} {} (#{length:05x}{})\n", path.x.abs() + path.y.abs(), direction(*hidden)));
+    }
+    return input;
+}
+
+fn direction(path: Coord) -> usize {
+    return DIRECTIONS.iter().position(|(x, y, _)| *x == path.x.signum() && *y == path.y.signum()).unwrap();
+}
+
+/// The `4 * columns` steps around columns of up to `width` by `height`, each overlapping the one before,
+/// so the trench never crosses itself. Neighbouring columns never line up, so the steps always turn.
+fn staircase(rng: &mut StdRng, columns: usize, width: i64, height: i64) -> Vec<Coord> {
+    let mut spans: Vec<(i64, i64, i64)> = vec![];
+    let mut x = 0;
+    while spans.len() < columns {
+        let bottom = rng.gen_range(0..height);
+        let top = rng.gen_range(bottom + 1..=height);
+        let fits = spans.last().is_none_or(|(_, previous_bottom, previous_top)| {
+            bottom != *previous_bottom && top != *previous_top && bottom.max(*previous_bottom) < top.min(*previous_top)
+        });
+        if fits {
+            x += rng.gen_range(1..=width);
+            spans.push((x, bottom, top));
+        }
+    }
+
+    let mut vertices = vec![Coord::new(0, spans[0].1)];
+    let mut left = 0;
+    for (right, _, top) in &spans {
+        vertices.push(Coord::new(left, *top));
+        vertices.push(Coord::new(*right, *top));
+        left = *right;
+    }
+    for (i, (right, bottom, _)) in spans.iter().enumerate().rev() {
+        let left = if i == 0 { 0 } else { spans[i - 1].0 };
+        vertices.push(Coord::new(*right, *bottom));
+        vertices.push(Coord::new(left, *bottom));
+    }
+    if rng.gen_bool(0.5) {
+        vertices.reverse();
+    }
+    return vertices.windows(2).map(|pair| pair[1] - pair[0]).collect();
+}
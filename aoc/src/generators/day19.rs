@@ -0,0 +1,48 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use super::{names, Generator};
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+pub const GENERATOR: Generator = Generator { generate, size: 500 };
+
+/// `size` workflows and `size` parts. The workflows form a tree from `in`, so every part ends up
+/// accepted or rejected.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = size.max(1);
+    let mut labels = vec!["in".to_string()];
+    labels.extend(names(rng, count - 1, 3, LETTERS, &["in"]));
+    let mut children: Vec<Vec<String>> = vec![vec![]; count];
+    for (i, label) in labels.iter().enumerate().skip(1) {
+        children[rng.gen_range(0..i)].push(label.clone());
+    }
+
+    let mut lines = vec![];
+    for (label, children) in labels.iter().zip(children) {
+        let rule_count = children.len().saturating_sub(1).max(rng.gen_range(1..=3));
+        let mut destinations = children;
+        while destinations.len() < rule_count + 1 {
+            destinations.push(if rng.gen_bool(0.5) { "A" } else { "R" }.to_string());
+        }
+        destinations.shuffle(rng);
+        let else_label = destinations.pop().unwrap();
+        let rules: Vec<String> = destinations.iter()
+            .map(|destination| {
+                let category = CATEGORIES.choose(rng).unwrap();
+                let op = if rng.gen_bool(0.5) { '<' } else { '>' };
+                format!("{category}{op}{}:{destination}", rng.gen_range(1..=4000))
+            })
+            .collect();
+        lines.push(format!("{label}{{{},{else_label}}}", rules.join(",")));
+    }
+    lines.shuffle(rng);
+
+    let mut input = lines.join("\n") + "\n\n";
+    for _ in 0..count {
+        let ratings: Vec<String> = CATEGORIES.iter().map(|category| format!("{category}={}", rng.gen_range(1..=4000))).collect();
+        input.push_str(&format!("{{{}}}\n", ratings.join(",")));
+    }
+    return input;
+}
@@ -0,0 +1,53 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use super::{names, Generator};
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+const BITS: usize = 12;
+
+pub const GENERATOR: Generator = Generator { generate, size: 4 };
+
+/// A network of `size` twelve-bit counters, at most eight, built like the puzzle's.
+///
+/// Each counter is a chain of flip-flops with a conjunction that resets it whenever it reaches its
+/// period, a prime. Every counter's conjunction reports through an inverter to `dt`, which feeds `rx`.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let counters = size.clamp(1, 8);
+    let primes: Vec<usize> = ((1 << (BITS - 1)) + 1..1 << BITS).step_by(2).filter(|n| is_prime(*n)).collect();
+    let periods: Vec<usize> = primes.choose_multiple(rng, counters).copied().collect();
+    let mut names = names(rng, counters * (BITS + 2), 2, LETTERS, &["rx", "dt"]).into_iter();
+
+    let mut lines = vec![];
+    let mut starts = vec![];
+    for period in periods {
+        let flip_flops: Vec<String> = names.by_ref().take(BITS).collect();
+        let hub = names.next().unwrap();
+        let inverter = names.next().unwrap();
+        starts.push(flip_flops[0].clone());
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = vec![];
+            if bit + 1 < BITS {
+                outputs.push(flip_flops[bit + 1].clone());
+            }
+            if period >> bit & 1 == 1 {
+                outputs.push(hub.clone());
+            }
+            lines.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+        let mut hub_outputs: Vec<String> = (0..BITS)
+            .filter(|bit| period >> bit & 1 == 0 || *bit == 0)
+            .map(|bit| flip_flops[bit].clone())
+            .collect();
+        hub_outputs.push(inverter.clone());
+        lines.push(format!("&{hub} -> {}", hub_outputs.join(", ")));
+        lines.push(format!("&{inverter} -> dt"));
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.push("&dt -> rx".to_string());
+    lines.shuffle(rng);
+    return lines.join("\n") + "\n";
+}
+
+fn is_prime(n: usize) -> bool {
+    return (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d));
+}
@@ -0,0 +1,108 @@
+//! Every day's solution behind one interface, so they can be run, checked, timed, drawn, played and fed random
+//! inputs by number.
+
+use std::time::{Duration, Instant};
+use tracing::info_span;
+use common::{ParseError, Picture, Simulation, Solution, SolveError};
+use generators::Generator;
+
+pub mod animation;
+pub mod answers;
+pub mod bench;
+pub mod generators;
+pub mod report;
+
+/// The phases of a solution, in the order they run.
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// A type-erased handle on a day's solution, so days can be looked up and run by number.
+pub struct Day {
+    /// The day of the event.
+    pub number: u8,
+    /// Parses an input and answers both parts, timing each phase.
+    pub solve: fn(&str) -> Result<Run, ParseError>,
+    /// The day's picture of an input, if it draws one.
+    pub render: fn(&str) -> Result<Option<Picture>, ParseError>,
+    /// The day's simulation of an input, if it plays out step by step.
+    pub simulate: Simulate,
+    /// How to make up random inputs for the day.
+    pub generator: Generator,
+}
+
+/// Parses an input and sets up a day's simulation of it, for the days that have one.
+pub type Simulate = fn(&str) -> Result<Option<Box<dyn Simulation>>, ParseError>;
+
+/// The answers from one run of a day, with how long each of its [`PHASES`] took.
+pub struct Run {
+    /// Each part's answer, or why it has none.
+    pub answers: [Result<String, SolveError>; 2],
+    /// How long parsing and each part took.
+    pub elapsed: [Duration; 3],
+}
+
+impl Run {
+    /// Both answers, or why the first part without one has none.
+    pub fn both_answers(&self) -> Result<[String; 2], String> {
+        let [part1, part2] = &self.answers;
+        return match (part1, part2) {
+            (Ok(part1), Ok(part2)) => Ok([part1.clone(), part2.clone()]),
+            (Err(e), _) => Err(format!("part1 has no answer, {e}")),
+            (_, Err(e)) => Err(format!("part2 has no answer, {e}")),
+        };
+    }
+}
+
+const fn day<S: Solution>(generator: Generator) -> Day {
+    return Day { number: S::DAY, solve: solve::<S>, render: render::<S>, simulate: simulate::<S>, generator };
+}
+
+fn render<S: Solution>(input: &str) -> Result<Option<Picture>, ParseError> {
+    return Ok(S::render(&S::parse(input)?));
+}
+
+fn simulate<S: Solution>(input: &str) -> Result<Option<Box<dyn Simulation>>, ParseError> {
+    return Ok(S::simulate(&S::parse(input)?));
+}
+
+fn solve<S: Solution>(input: &str) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let parsed = info_span!("parse", day = S::DAY).in_scope(|| S::parse(input))?;
+    let parsed_at = Instant::now();
+    let part1 = info_span!("part1", day = S::DAY).in_scope(|| S::part1(&parsed));
+    let part1_at = Instant::now();
+    let part2 = info_span!("part2", day = S::DAY).in_scope(|| S::part2(&parsed));
+    let part2_at = Instant::now();
+    return Ok(Run {
+        answers: [part1, part2],
+        elapsed: [parsed_at - start, part1_at - parsed_at, part2_at - part1_at],
+    });
+}
+
+/// Every day with a solution, in order.
+pub const DAYS: [Day; 20] = [
+    day::<day01::Day01>(generators::day01::GENERATOR),
+    day::<day02::Day02>(generators::day02::GENERATOR),
+    day::<day03::Day03>(generators::day03::GENERATOR),
+    day::<day04::Day04>(generators::day04::GENERATOR),
+    day::<day05::Day05>(generators::day05::GENERATOR),
+    day::<day06::Day06>(generators::day06::GENERATOR),
+    day::<day07::Day07>(generators::day07::GENERATOR),
+    day::<day08::Day08>(generators::day08::GENERATOR),
+    day::<day09::Day09>(generators::day09::GENERATOR),
+    day::<day10::Day10>(generators::day10::GENERATOR),
+    day::<day11::Day11>(generators::day11::GENERATOR),
+    day::<day12::Day12>(generators::day12::GENERATOR),
+    day::<day13::Day13>(generators::day13::GENERATOR),
+    day::<day14::Day14>(generators::day14::GENERATOR),
+    day::<day15::Day15>(generators::day15::GENERATOR),
+    day::<day16::Day16>(generators::day16::GENERATOR),
+    day::<day17::Day17>(generators::day17::GENERATOR),
+    day::<day18::Day18>(generators::day18::GENERATOR),
+    day::<day19::Day19>(generators::day19::GENERATOR),
+    day::<day20::Day20>(generators::day20::GENERATOR),
+];
+
+/// The day numbered `number`, if it has a solution.
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    return DAYS.iter().find(|d| d.number == number);
+}
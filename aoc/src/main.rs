@@ -0,0 +1,467 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::str::FromStr;
+use std::time::Duration;
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+use common::{input_sources, print_star, InputSource};
+use aoc::answers::{answers_path, input_path, stored_inputs, Answers};
+use aoc::report::Report;
+use aoc::animation::play;
+use aoc::generators::generate;
+use aoc::bench::{baseline_key, format_nanos, load_baseline, save_baseline, Baseline, Stats};
+use aoc::{find_day, Day, DAYS, PHASES};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log to stderr what the solutions are doing: `-v` for each phase and how long it took, `-vv` for what
+    /// their searches and cycle detection found, `-vvv` for every step of their inner loops
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with `all`
+    Run {
+        /// Day number (1-20) or `all`
+        day: Selection,
+        /// Input files to solve, `-` for stdin. Defaults to piped stdin, then `dayNN/input.txt`
+        inputs: Vec<String>,
+        /// `json` prints one JSON object per input, with answers, timings and an input hash
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Time each phase of a day, or of every day with `all`, over many runs
+    Bench {
+        /// Day number (1-20) or `all`
+        day: Selection,
+        /// Input files to time, as for `run`
+        inputs: Vec<String>,
+        /// How many times to run each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// JSON file holding the timings to compare against
+        #[arg(long, default_value = "bench-baseline.json")]
+        baseline: PathBuf,
+        /// Store these timings in the baseline file
+        #[arg(long)]
+        save: bool,
+        /// Flag phases whose median got slower than the baseline by more than this many percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check a day, or every day with `all`, against the answers stored under `answers/`
+    Verify {
+        /// Day number (1-20) or `all`
+        #[arg(default_value = "all")]
+        day: Selection,
+    },
+    /// Store the current answers for some inputs as `answers/dayNN/<input-name>.toml`
+    Record {
+        /// Day number (1-20) or `all`
+        day: Selection,
+        /// Input files to record, as for `run`. Verifying reads them back from `dayNN/<input-name>.txt`
+        inputs: Vec<String>,
+    },
+    /// Print a random input for a day, which is the same every time for the same seed and size
+    Gen {
+        /// Day number (1-20)
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big to make the input, in lines, cards, ghosts or whatever else the day has many of.
+        /// Defaults to about the size of a real input
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Draw an input of day 10, 14, 16, 17 or 18 as an SVG or PNG picture
+    Render {
+        /// Day number (1-20)
+        day: u8,
+        /// Input file to draw, as for `run`
+        input: Option<String>,
+        /// Where to write the picture. A `.png` extension writes PNG, anything else SVG
+        #[arg(long, short)]
+        output: PathBuf,
+        /// Pixels per grid cell, for PNG
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+    },
+    /// Play day 14, 16 or 20 step by step in the terminal
+    Animate {
+        /// Day number (1-20)
+        day: u8,
+        /// Input file to play, as for `run`
+        input: Option<String>,
+        /// Steps per second, which `+` and `-` change while playing
+        #[arg(long, default_value_t = 10.0)]
+        speed: f64,
+        /// The step to start from
+        #[arg(long, default_value_t = 0)]
+        from: usize,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Clone)]
+enum Selection {
+    All,
+    Single(&'static Day),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+        let number: u8 = s.parse().map_err(|_| format!("expected a day number or `all`, got `{s}`"))?;
+        return find_day(number)
+            .map(Selection::Single)
+            .ok_or(format!("no solution for day {number}"));
+    }
+}
+
+fn default_input(day: &Day) -> PathBuf {
+    return PathBuf::from(format!("day{:02}/input.txt", day.number));
+}
+
+/// Pairs each selected day with the inputs to run it on.
+fn sources(selection: Selection, inputs: &[String]) -> Vec<(&'static Day, InputSource)> {
+    return match selection {
+        Selection::Single(day) => input_sources(inputs, &default_input(day))
+            .into_iter()
+            .map(|source| (day, source))
+            .collect(),
+        Selection::All => {
+            if !inputs.is_empty() {
+                eprintln!("Input files can only be given when running a single day");
+                exit(2);
+            }
+            DAYS.iter().map(|day| (day, InputSource::File(default_input(day)))).collect()
+        }
+    };
+}
+
+/// The input, or `None` after reporting why it could not be read, so the other days still run.
+fn read(source: &InputSource) -> Option<String> {
+    return match source.read() {
+        Ok(input) => Some(input),
+        Err(e) => {
+            eprintln!("Could not read {source}: {e}");
+            None
+        }
+    };
+}
+
+fn run(day: &Day, source: &InputSource, format: Format) -> bool {
+    let Some(input) = read(source) else {
+        return false;
+    };
+    let result = (day.solve)(&input);
+    if let Format::Json = format {
+        println!("{}", Report::new(day.number, &source.to_string(), &input, &result).to_json());
+        return result.is_ok_and(|run| run.answers.iter().all(Result::is_ok));
+    }
+    println!("Day {:02} ({source})", day.number);
+    return match result {
+        Ok(run) => {
+            let [first, second] = run.answers;
+            let first = print_star("First", first);
+            let second = print_star("Second", second);
+            first && second
+        }
+        Err(e) => {
+            eprintln!("{source}: {}", e.report(&input));
+            false
+        }
+    };
+}
+
+/// Times `runs` runs of a day and compares them with the baseline, returning whether nothing regressed.
+fn bench(day: &Day, source: &InputSource, runs: u32, baseline: &mut Baseline, save: bool, threshold: f64) -> bool {
+    let Some(input) = read(source) else {
+        return false;
+    };
+    println!("Day {:02} ({source}), {runs} runs", day.number);
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..runs {
+        match (day.solve)(&input) {
+            Ok(run) => {
+                if let Err(e) = run.both_answers() {
+                    eprintln!("{source}: {e}");
+                    return false;
+                }
+                for (phase, elapsed) in run.elapsed.iter().enumerate() {
+                    samples[phase].push(*elapsed);
+                }
+            }
+            Err(e) => {
+                eprintln!("{source}: {}", e.report(&input));
+                return false;
+            }
+        }
+    }
+
+    let key = baseline_key(day.number, &source.to_string());
+    let mut timings = BTreeMap::new();
+    let mut regressed = false;
+    for (phase, samples) in PHASES.iter().zip(&samples) {
+        let stats = Stats::of(samples);
+        let mut line = format!("  {phase:<5}  mean {:>9}  median {:>9}  std dev {:>9}",
+                               format_nanos(stats.mean), format_nanos(stats.median), format_nanos(stats.std_dev()));
+        if let Some(previous) = baseline.get(&key).and_then(|timings| timings.get(*phase)) {
+            match stats.regression(previous, threshold / 100.0) {
+                Some(slowdown) => {
+                    line.push_str(&format!("  REGRESSION: {:.0}% slower than the baseline median of {}",
+                                           slowdown * 100.0, format_nanos(previous.median)));
+                    regressed = true;
+                }
+                None => line.push_str(&format!("  (baseline median {})", format_nanos(previous.median))),
+            }
+        }
+        println!("{line}");
+        timings.insert(phase.to_string(), stats);
+    }
+    if save {
+        baseline.insert(key, timings);
+    }
+    return !regressed;
+}
+
+/// Reruns a day on one stored input. Returns `None` when the input is missing, else whether it matched.
+fn verify(day: &Day, name: &str) -> Option<bool> {
+    let input_path = input_path(day.number, name);
+    print!("day{:02} {name} ... ", day.number);
+    let Ok(input) = InputSource::File(input_path.clone()).read() else {
+        println!("missing {}", input_path.display());
+        return None;
+    };
+    let expected = match Answers::load(&answers_path(day.number, name)) {
+        Ok(expected) => expected,
+        Err(e) => {
+            println!("UNREADABLE ANSWERS\n  {e}");
+            return Some(false);
+        }
+    };
+    return match (day.solve)(&input) {
+        Ok(run) => match run.both_answers().map(|answers| expected.diff(&answers)) {
+            Ok(None) => {
+                println!("ok");
+                Some(true)
+            }
+            Ok(Some(diff)) => {
+                print!("MISMATCH\n{diff}");
+                Some(false)
+            }
+            Err(e) => {
+                println!("NO ANSWER\n  {e}");
+                Some(false)
+            }
+        },
+        Err(e) => {
+            println!("PARSE ERROR\n{}: {}", input_path.display(), e.report(&input));
+            Some(false)
+        }
+    };
+}
+
+fn record(day: &Day, source: &InputSource) -> bool {
+    let InputSource::File(path) = source else {
+        eprintln!("Answers can only be recorded for input files, not {source}");
+        return false;
+    };
+    let name = path.file_stem().unwrap_or_default().to_string_lossy();
+    if path != &input_path(day.number, &name) {
+        eprintln!("Warning: `verify` will look for this input at {}", input_path(day.number, &name).display());
+    }
+    let Some(input) = read(source) else {
+        return false;
+    };
+    let [part1, part2] = match (day.solve)(&input) {
+        Ok(run) => match run.both_answers() {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{source}: {e}");
+                return false;
+            }
+        },
+        Err(e) => {
+            eprintln!("{source}: {}", e.report(&input));
+            return false;
+        }
+    };
+    let path = answers_path(day.number, &name);
+    if let Err(e) = (Answers { part1, part2 }).save(&path) {
+        eprintln!("Could not save {}: {e}", path.display());
+        return false;
+    }
+    println!("Recorded {}", path.display());
+    return true;
+}
+
+fn render(day: &Day, source: &InputSource, output: &Path, scale: u32) -> bool {
+    let Some(input) = read(source) else {
+        return false;
+    };
+    let picture = match (day.render)(&input) {
+        Ok(Some(picture)) => picture,
+        Ok(None) => {
+            eprintln!("Day {} has nothing to draw", day.number);
+            return false;
+        }
+        Err(e) => {
+            eprintln!("{source}: {}", e.report(&input));
+            return false;
+        }
+    };
+    let written = if output.extension().is_some_and(|extension| extension == "png") {
+        File::create(output)
+            .map_err(|e| e.to_string())
+            .and_then(|file| picture.write_png(BufWriter::new(file), scale as usize).map_err(|e| e.to_string()))
+    } else {
+        fs::write(output, picture.to_svg()).map_err(|e| e.to_string())
+    };
+    if let Err(e) = written {
+        eprintln!("Could not write {}: {e}", output.display());
+        return false;
+    }
+    println!("Drew {source} to {}", output.display());
+    return true;
+}
+
+fn animate(day: &Day, source: &InputSource, speed: f64, from: usize) -> bool {
+    let Some(input) = read(source) else {
+        return false;
+    };
+    match (day.simulate)(&input) {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            eprintln!("Day {} has nothing to animate", day.number);
+            return false;
+        }
+        Err(e) => {
+            eprintln!("{source}: {}", e.report(&input));
+            return false;
+        }
+    }
+    // Starting over parses the input again, which already worked once
+    let start = || (day.simulate)(&input).unwrap().unwrap();
+    if let Err(e) = play(start, speed, from) {
+        eprintln!("Could not animate in this terminal: {e}");
+        return false;
+    }
+    return true;
+}
+
+fn single_day(number: u8) -> &'static Day {
+    return find_day(number).unwrap_or_else(|| {
+        eprintln!("No solution for day {number}");
+        exit(2);
+    });
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let level = match cli.verbose {
+        0 => Level::WARN,
+        1 => Level::INFO,
+        2 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .init();
+    let mut failed = false;
+    match cli.command {
+        Command::Run { day, inputs, format } => {
+            for (day, source) in sources(day, &inputs) {
+                failed |= !run(day, &source, format);
+            }
+        }
+        Command::Bench { day, inputs, runs, baseline: baseline_path, save, threshold } => {
+            let mut baseline = load_baseline(&baseline_path).unwrap_or_else(|e| {
+                eprintln!("Could not read the baseline {}: {e}", baseline_path.display());
+                exit(1);
+            });
+            for (day, source) in sources(day, &inputs) {
+                failed |= !bench(day, &source, runs, &mut baseline, save, threshold);
+            }
+            if save {
+                if let Err(e) = save_baseline(&baseline_path, &baseline) {
+                    eprintln!("Could not save the baseline {}: {e}", baseline_path.display());
+                    exit(1);
+                }
+                println!("Saved the timings to {}", baseline_path.display());
+            }
+        }
+        Command::Verify { day } => {
+            let days: Vec<&Day> = match day {
+                Selection::Single(day) => vec![day],
+                Selection::All => DAYS.iter().collect(),
+            };
+            let (mut passed, mut mismatched, mut missing) = (0, 0, 0);
+            for day in days {
+                let names = match stored_inputs(day.number) {
+                    Ok(names) => names,
+                    Err(e) => {
+                        eprintln!("Could not list the answers for day {}: {e}", day.number);
+                        mismatched += 1;
+                        continue;
+                    }
+                };
+                for name in names {
+                    match verify(day, &name) {
+                        Some(true) => passed += 1,
+                        Some(false) => mismatched += 1,
+                        None => missing += 1,
+                    }
+                }
+            }
+            println!("{passed} passed, {mismatched} failed, {missing} missing inputs");
+            failed = mismatched > 0 || missing > 0;
+        }
+        Command::Record { day, inputs } => {
+            for (day, source) in sources(day, &inputs) {
+                failed |= !record(day, &source);
+            }
+        }
+        Command::Gen { day, seed, size } => {
+            let day = single_day(day);
+            print!("{}", generate(day, seed, size.unwrap_or(day.generator.size)));
+        }
+        Command::Render { day, input, output, scale } => {
+            let day = single_day(day);
+            let inputs: Vec<String> = input.into_iter().collect();
+            for source in input_sources(&inputs, &default_input(day)) {
+                failed |= !render(day, &source, &output, scale);
+            }
+        }
+        Command::Animate { day, input, speed, from } => {
+            let day = single_day(day);
+            let inputs: Vec<String> = input.into_iter().collect();
+            let sources = input_sources(&inputs, &default_input(day));
+            failed |= !animate(day, &sources[0], speed, from);
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
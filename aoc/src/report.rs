@@ -0,0 +1,126 @@
+//! The JSON lines printed by `run --format json`.
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use common::ParseError;
+use crate::Run;
+
+/// One solved (or unparseable) input, as printed on a single line by `run --format json`.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// The day solved.
+    pub day: u8,
+    /// Where the input was read from.
+    pub input: String,
+    /// SHA-256 of the input, to tell apart runs on different inputs with the same path.
+    pub input_sha256: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// How long parsing took, unless it failed.
+    pub parse_elapsed_ns: Option<u64>,
+    /// Each part's answer and timing, unless parsing failed.
+    pub parts: Vec<PartReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Why the input could not be parsed.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+/// One part of a [`Report`].
+pub struct PartReport {
+    /// The part number, 1 or 2.
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The answer, unless the part has none.
+    pub answer: Option<String>,
+    /// Why the part has no answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// How long the part took.
+    pub elapsed_ns: u64,
+}
+
+impl Report {
+    /// The report on `input`, read from `source`, from what solving it gave.
+    pub fn new(day: u8, source: &str, input: &str, result: &Result<Run, ParseError>) -> Report {
+        let mut report = Report {
+            day,
+            input: source.to_string(),
+            input_sha256: sha256(input),
+            parse_elapsed_ns: None,
+            parts: vec![],
+            error: None,
+        };
+        match result {
+            Ok(run) => {
+                report.parse_elapsed_ns = Some(run.elapsed[0].as_nanos() as u64);
+                for (i, answer) in run.answers.iter().enumerate() {
+                    report.parts.push(PartReport {
+                        part: i as u8 + 1,
+                        answer: answer.as_ref().ok().cloned(),
+                        error: answer.as_ref().err().map(|e| e.to_string()),
+                        elapsed_ns: run.elapsed[i + 1].as_nanos() as u64,
+                    });
+                }
+            }
+            Err(e) => report.error = Some(e.to_string()),
+        }
+        return report;
+    }
+
+    /// The report as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        return serde_json::to_string(self).unwrap();
+    }
+}
+
+/// The SHA-256 of `input`, as lowercase hex.
+pub fn sha256(input: &str) -> String {
+    return Sha256::digest(input.as_bytes()).iter().map(|byte| format!("{byte:02x}")).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use common::SolveError;
+    use super::*;
+
+    #[test]
+    fn hashes_inputs() {
+        assert_eq!(sha256("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn reports_answers_and_timings() {
+        let run = Run {
+            answers: [Ok("6440".to_string()), Ok("5905".to_string())],
+            elapsed: [Duration::from_nanos(10), Duration::from_nanos(20), Duration::from_nanos(30)],
+        };
+        let json = Report::new(7, "day07/example.txt", "abc", &Ok(run)).to_json();
+        assert_eq!(json, concat!(
+            r#"{"day":7,"input":"day07/example.txt","#,
+            r#""input_sha256":"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad","#,
+            r#""parse_elapsed_ns":10,"parts":[{"part":1,"answer":"6440","elapsed_ns":20},"#,
+            r#"{"part":2,"answer":"5905","elapsed_ns":30}]}"#,
+        ));
+    }
+
+    #[test]
+    fn reports_parts_without_answers() {
+        let run = Run {
+            answers: [Ok("32".to_string()), Err(SolveError::new("no module sends pulses to rx"))],
+            elapsed: [Duration::from_nanos(10), Duration::from_nanos(20), Duration::from_nanos(30)],
+        };
+        let json = Report::new(20, "day20/example.txt", "abc", &Ok(run)).to_json();
+        assert!(json.ends_with(concat!(
+            r#""parts":[{"part":1,"answer":"32","elapsed_ns":20},"#,
+            r#"{"part":2,"error":"no module sends pulses to rx","elapsed_ns":30}]}"#,
+        )));
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let error = ParseError::end_of_input(0, "a hand");
+        let json = Report::new(7, "<stdin>", "", &Err(error)).to_json();
+        assert!(json.ends_with(r#""parts":[],"error":"line 1, column 1: expected a hand, found end of input"}"#));
+    }
+}
@@ -0,0 +1,102 @@
+//! Checks every day against the example inputs in `fixtures/dayNN/`.
+//!
+//! Each `<name>.txt` input sits next to a `<name>.toml` listing the expected `part1` and `part2`
+//! answers. Either can be left out, as some examples only come with an answer for one part. Every example is
+//! also solved as if saved on Windows, with a byte order mark, `\r\n` line endings and trailing whitespace.
+
+use std::fs;
+use std::path::PathBuf;
+use serde::Deserialize;
+use common::Solution;
+
+#[derive(Clone, Deserialize)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+fn fixtures(day: u8) -> Vec<(String, String, Expected)> {
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("../fixtures/day{day:02}"));
+    let mut fixtures = vec![];
+    for entry in fs::read_dir(&directory).unwrap_or_else(|e| panic!("Cannot read {}: {e}", directory.display())) {
+        let path = entry.unwrap().path();
+        if path.extension().is_none_or(|extension| extension != "toml") {
+            continue;
+        }
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        let expected: Expected = toml::from_str(&fs::read_to_string(&path).unwrap())
+            .unwrap_or_else(|e| panic!("Invalid answers in {}: {e}", path.display()));
+        let input = fs::read_to_string(path.with_extension("txt"))
+            .unwrap_or_else(|e| panic!("No input for {}: {e}", path.display()));
+        fixtures.push((format!("{name} (Windows)"), windows(&input), expected.clone()));
+        fixtures.push((name, input, expected));
+    }
+    fixtures.sort_by(|a, b| a.0.cmp(&b.0));
+    return fixtures;
+}
+
+/// `input` as an editor on Windows might save it.
+fn windows(input: &str) -> String {
+    let lines: Vec<String> = input.lines().map(|line| format!("{line} \t")).collect();
+    return format!("\u{feff}{}\r\n\r\n", lines.join("\r\n"));
+}
+
+fn check<S: Solution>() {
+    let fixtures = fixtures(S::DAY);
+    assert!(!fixtures.is_empty(), "No fixtures for day {:02}", S::DAY);
+
+    let mut failures = vec![];
+    for (name, input, expected) in fixtures {
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                failures.push(format!("{name}: {}", e.report(&input)));
+                continue;
+            }
+        };
+        for (part, expected) in [expected.part1, expected.part2].into_iter().enumerate() {
+            let Some(expected) = expected else { continue };
+            let actual = if part == 0 { S::part1(&parsed) } else { S::part2(&parsed) };
+            match actual {
+                Ok(actual) if actual == expected => {}
+                Ok(actual) => failures.push(format!("{name} part {}: expected {expected}, got {actual}", part + 1)),
+                Err(e) => failures.push(format!("{name} part {}: expected {expected}, got no answer, {e}", part + 1)),
+            }
+        }
+    }
+    assert!(failures.is_empty(), "Day {:02} examples failed:\n{}", S::DAY, failures.join("\n"));
+}
+
+macro_rules! examples {
+    ($($test:ident: $day:ty,)*) => {
+        $(
+            #[test]
+            fn $test() {
+                check::<$day>();
+            }
+        )*
+    };
+}
+
+examples! {
+    day01: day01::Day01,
+    day02: day02::Day02,
+    day03: day03::Day03,
+    day04: day04::Day04,
+    day05: day05::Day05,
+    day06: day06::Day06,
+    day07: day07::Day07,
+    day08: day08::Day08,
+    day09: day09::Day09,
+    day10: day10::Day10,
+    day11: day11::Day11,
+    day12: day12::Day12,
+    day13: day13::Day13,
+    day14: day14::Day14,
+    day15: day15::Day15,
+    day16: day16::Day16,
+    day17: day17::Day17,
+    day18: day18::Day18,
+    day19: day19::Day19,
+    day20: day20::Day20,
+}
@@ -0,0 +1,76 @@
+//! Runs every day on small generated inputs, and checks the days with brute-force references against them.
+
+use common::Solution;
+use aoc::generators::generate;
+use aoc::{find_day, Day, DAYS};
+
+const SEEDS: std::ops::Range<u64> = 0..5;
+
+/// A tenth of a real input keeps the slower days quick enough in debug builds.
+fn small_input(number: u8, seed: u64) -> String {
+    let day = find_day(number).unwrap();
+    return generate(day, seed, (day.generator.size / 10).max(1));
+}
+
+#[test]
+fn same_seed_gives_same_input() {
+    for day in &DAYS {
+        assert_eq!(generate(day, 7, 3), generate(day, 7, 3), "day {:02}", day.number);
+    }
+}
+
+/// Parses and solves `input`, which must give both answers.
+fn assert_solves(day: &Day, input: &str, what: &str) {
+    match (day.solve)(input) {
+        Ok(run) => if let Err(e) = run.both_answers() {
+            panic!("Day {:02} cannot solve {what}: {e}", day.number);
+        },
+        Err(e) => panic!("Day {:02} cannot parse {what}: {}", day.number, e.report(input)),
+    }
+}
+
+#[test]
+fn every_day_solves_its_generated_inputs() {
+    for day in &DAYS {
+        for seed in SEEDS {
+            assert_solves(day, &small_input(day.number, seed), &format!("seed {seed}"));
+        }
+    }
+}
+
+#[test]
+fn every_day_solves_the_smallest_sizes() {
+    for day in &DAYS {
+        for size in 0..5 {
+            assert_solves(day, &generate(day, 0, size), &format!("size {size}"));
+        }
+    }
+}
+
+#[test]
+fn day05_matches_reference() {
+    for seed in SEEDS {
+        let almanac = day05::Day05::parse(&small_input(5, seed)).unwrap();
+        assert_eq!(day05::Day05::part2(&almanac), Ok(day05::reference::lowest_location(&almanac).unwrap().to_string()), "seed {seed}");
+    }
+}
+
+#[test]
+fn day12_matches_reference() {
+    for seed in SEEDS {
+        let rows = day12::Day12::parse(&small_input(12, seed)).unwrap();
+        let expected: i64 = rows.iter()
+            .map(|(line, counts)| day12::reference::count_arrangements(line, &counts.iter().copied().collect::<Vec<_>>()))
+            .sum();
+        assert_eq!(day12::Day12::part1(&rows), Ok(expected.to_string()), "seed {seed}");
+    }
+}
+
+#[test]
+fn day18_matches_reference() {
+    for seed in SEEDS {
+        let instructions = day18::Day18::parse(&small_input(18, seed)).unwrap();
+        let expected = day18::reference::lagoon_size(instructions.iter().map(|instruction| instruction.path));
+        assert_eq!(day18::Day18::part1(&instructions), Ok(expected.to_string()), "seed {seed}");
+    }
+}
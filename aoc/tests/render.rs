@@ -0,0 +1,41 @@
+//! Draws the example inputs of the days that have pictures.
+
+use std::fs;
+use std::path::PathBuf;
+use aoc::DAYS;
+
+const DRAWN: [u8; 5] = [10, 14, 16, 17, 18];
+
+/// The first of a day's examples in `fixtures/dayNN/`.
+fn example(day: u8) -> String {
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("../fixtures/day{day:02}"));
+    let mut inputs: Vec<PathBuf> = fs::read_dir(&directory).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    inputs.sort();
+    return fs::read_to_string(&inputs[0]).unwrap();
+}
+
+#[test]
+fn only_some_days_draw_pictures() {
+    for day in &DAYS {
+        let picture = (day.render)(&example(day.number)).unwrap();
+        assert_eq!(picture.is_some(), DRAWN.contains(&day.number), "day {:02}", day.number);
+        if let Some(picture) = picture {
+            assert!(picture.to_svg().ends_with("</svg>\n"));
+            let pixels = picture.to_pixels(2);
+            assert_eq!((pixels.width(), pixels.height()), (picture.width() * 2, picture.height() * 2));
+        }
+    }
+}
+
+#[test]
+fn grid_pictures_have_a_cell_per_tile() {
+    for number in [10, 14, 16] {
+        let input = example(number);
+        let picture = (DAYS[number as usize - 1].render)(&input).unwrap().unwrap();
+        let width = input.lines().next().unwrap().len();
+        assert_eq!((picture.width(), picture.height()), (width, input.lines().count()), "day {number:02}");
+    }
+}
@@ -0,0 +1,55 @@
+//! Checks that the step by step simulations end up where the solvers do.
+
+use std::fs;
+use std::path::PathBuf;
+use common::{Simulation, Solution};
+use aoc::DAYS;
+
+fn example(day: u8) -> String {
+    return fs::read_to_string(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("../fixtures/day{day:02}/example.txt"))).unwrap();
+}
+
+#[test]
+fn only_some_days_simulate() {
+    for day in &DAYS {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("../fixtures/day{:02}/example.txt", day.number));
+        let Ok(input) = fs::read_to_string(path) else {
+            continue;
+        };
+        assert_eq!((day.simulate)(&input).unwrap().is_some(), [14, 16, 20].contains(&day.number), "day {:02}", day.number);
+    }
+}
+
+#[test]
+fn day14_tilts_make_spin_cycles() {
+    let platform = day14::Day14::parse(&example(14)).unwrap();
+    let mut tilting = day14::Tilting { platform: platform.clone(), tilts: 0 };
+    let mut spun = platform;
+    for _ in 0..3 {
+        for _ in 0..4 {
+            tilting.step();
+        }
+        spun = day14::spin_cycle(&spun);
+        assert_eq!(tilting.frame(), spun.to_string());
+    }
+    assert_eq!(tilting.status(), format!("Cycle 3, tilted east, load {}", day14::total_load(&spun)));
+}
+
+#[test]
+fn day16_beam_energizes_part1_tiles() {
+    let grid = day16::Day16::parse(&example(16)).unwrap();
+    let mut beam = day16::Beam::new(&grid);
+    while beam.step() {}
+    assert_eq!(Ok(beam.energized.len().to_string()), day16::Day16::part1(&grid));
+    assert!(beam.fronts.is_empty());
+}
+
+#[test]
+fn day20_pulses_count_like_part1() {
+    let graph = day20::Day20::parse(&example(20)).unwrap();
+    let mut pulses = day20::Pulses::new(&graph);
+    while pulses.presses < 1000 || !pulses.queue.is_empty() {
+        pulses.step();
+    }
+    assert_eq!(Ok((pulses.low_count * pulses.high_count).to_string()), day20::Day20::part1(&graph));
+}
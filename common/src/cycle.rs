@@ -0,0 +1,113 @@
+use tracing::debug;
+
+/// Where a deterministic sequence of states starts repeating itself.
+///
+/// The state after `start` steps is the first one that comes back, and it does so every `period` steps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    /// How many steps it takes to reach the first state that repeats.
+    pub start: usize,
+    /// How many steps it takes to come back to it.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Runs `step` from `initial` until a state repeats, using Brent's algorithm.
+    ///
+    /// Only a couple of states are held at any time, so this also suits large states like whole grids.
+    pub fn find<S: Clone + Eq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+        let mut power = 1;
+        let mut period = 1;
+        let mut tortoise = initial.clone();
+        let mut hare = step(initial);
+        while tortoise != hare {
+            if power == period {
+                tortoise = hare.clone();
+                power *= 2;
+                period = 0;
+            }
+            hare = step(&hare);
+            period += 1;
+        }
+
+        let mut tortoise = initial.clone();
+        let mut hare = initial.clone();
+        for _ in 0..period {
+            hare = step(&hare);
+        }
+        let mut start = 0;
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&hare);
+            start += 1;
+        }
+
+        debug!(start, period, "found a cycle");
+        return Cycle { start, period };
+    }
+
+    /// The smallest step count that ends in the same state as `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            return n;
+        }
+        return self.start + (n - self.start) % self.period;
+    }
+
+    /// The state after `n` steps from `initial`, simulating at most `start + period` of them.
+    pub fn state_at<S: Clone>(&self, initial: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        let mut state = initial.clone();
+        for _ in 0..self.reduce(n) {
+            state = step(&state);
+        }
+        return state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2 -> ...
+    fn step(state: &u32) -> u32 {
+        return if *state == 5 { 2 } else { state + 1 };
+    }
+
+    #[test]
+    fn finds_start_and_period() {
+        assert_eq!(Cycle::find(&0, step), Cycle { start: 2, period: 4 });
+        assert_eq!(Cycle::find(&3, step), Cycle { start: 0, period: 4 });
+        assert_eq!(Cycle::find(&7, |_| 7), Cycle { start: 0, period: 1 });
+    }
+
+    #[test]
+    fn finds_long_cycles() {
+        let step = |x: &u64| (x * x + 1) % 255;
+        let cycle = Cycle::find(&3, step);
+
+        let mut seen = vec![3];
+        let mut state = 3;
+        loop {
+            state = step(&state);
+            if let Some(start) = seen.iter().position(|s| *s == state) {
+                assert_eq!(cycle, Cycle { start, period: seen.len() - start });
+                break;
+            }
+            seen.push(state);
+        }
+    }
+
+    #[test]
+    fn jumps_to_any_step() {
+        let cycle = Cycle::find(&0, step);
+        for n in 0..50 {
+            let mut state = 0;
+            for _ in 0..n {
+                state = step(&state);
+            }
+            assert_eq!(cycle.state_at(&0, step, n), state);
+        }
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(1_000_000_000), 4);
+    }
+}
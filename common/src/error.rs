@@ -0,0 +1,108 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A malformed or truncated puzzle input, pointing at the offending token.
+///
+/// Lines and columns are 1-based and columns count characters, so they match what an editor shows.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// The line the error is on.
+    pub line: usize,
+    /// The column where the offending token starts.
+    pub column: usize,
+    /// What was there instead.
+    pub found: Found,
+    /// A description of what should have been there.
+    pub expected: String,
+}
+
+/// What a parser ran into instead of the form it expected.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Found {
+    /// A token that does not fit.
+    Token(String),
+    /// The end of the line, when the line stops too early.
+    EndOfLine,
+    /// The end of the input, when lines are missing.
+    EndOfInput,
+}
+
+impl ParseError {
+    /// An error about an unexpected `token`.
+    pub fn new(line: usize, column: usize, token: &str, expected: &str) -> ParseError {
+        return ParseError { line, column, found: Found::Token(token.to_owned()), expected: expected.to_owned() };
+    }
+
+    /// Error for `token`, which must be a slice of `text`, the content of line number `line`.
+    pub fn at(line: usize, text: &str, token: &str, expected: &str) -> ParseError {
+        let start = text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= text.len())
+            .or_else(|| text.find(token))
+            .unwrap_or(0);
+        return ParseError::new(line, text[..offset].chars().count() + 1, token, expected);
+    }
+
+    /// Error for a line that stopped before `expected` showed up.
+    pub fn end_of_line(line: usize, text: &str, expected: &str) -> ParseError {
+        let column = text.chars().count() + 1;
+        return ParseError { line, column, found: Found::EndOfLine, expected: expected.to_owned() };
+    }
+
+    /// Error for an input that stopped after `line_count` lines, before `expected` showed up.
+    pub fn end_of_input(line_count: usize, expected: &str) -> ParseError {
+        return ParseError { line: line_count + 1, column: 1, found: Found::EndOfInput, expected: expected.to_owned() };
+    }
+
+    /// Renders the error together with the line it points at and a marker under the token.
+    pub fn report(&self, input: &str) -> String {
+        let mut report = format!("error: {self}");
+        if let Some(text) = input.lines().nth(self.line - 1) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let width = match &self.found {
+                Found::Token(token) => token.chars().count().max(1),
+                Found::EndOfLine | Found::EndOfInput => 1,
+            };
+            let marker = "^".repeat(width);
+            report.push_str(&format!("\n{gutter} |\n{} | {text}\n{gutter} | {}{marker}",
+                                     self.line, " ".repeat(self.column - 1)));
+        }
+        return report;
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: expected {}, found ", self.line, self.column, self.expected)?;
+        return match &self.found {
+            Found::Token(token) => write!(f, "`{token}`"),
+            Found::EndOfLine => write!(f, "end of line"),
+            Found::EndOfInput => write!(f, "end of input"),
+        };
+    }
+}
+
+impl Error for ParseError {}
+
+/// Why an input that parsed has no answer for one of the stars.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolveError {
+    /// What is wrong with the input.
+    pub message: String,
+}
+
+impl SolveError {
+    /// An error with the given message.
+    pub fn new(message: &str) -> SolveError {
+        return SolveError { message: message.to_owned() };
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.message);
+    }
+}
+
+impl Error for SolveError {}
@@ -0,0 +1,289 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::{Grid, Position};
+
+/// One of the four grid directions, with `UP` pointing towards row 0.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    /// Towards row 0.
+    UP,
+    /// Away from row 0.
+    DOWN,
+    /// Towards column 0.
+    LEFT,
+    /// Away from column 0.
+    RIGHT,
+}
+
+impl Direction {
+    /// The four directions.
+    pub const ALL: [Direction; 4] = [Direction::UP, Direction::DOWN, Direction::LEFT, Direction::RIGHT];
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        return match self {
+            Direction::UP => Direction::LEFT,
+            Direction::LEFT => Direction::DOWN,
+            Direction::DOWN => Direction::RIGHT,
+            Direction::RIGHT => Direction::UP,
+        };
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        return match self {
+            Direction::UP => Direction::RIGHT,
+            Direction::RIGHT => Direction::DOWN,
+            Direction::DOWN => Direction::LEFT,
+            Direction::LEFT => Direction::UP,
+        };
+    }
+
+    /// The direction back.
+    pub fn opposite(self) -> Direction {
+        return match self {
+            Direction::UP => Direction::DOWN,
+            Direction::DOWN => Direction::UP,
+            Direction::LEFT => Direction::RIGHT,
+            Direction::RIGHT => Direction::LEFT,
+        };
+    }
+
+    /// The direction a beam travelling this way leaves a `/` mirror in.
+    pub fn reflect_slash(self) -> Direction {
+        return match self {
+            Direction::UP => Direction::RIGHT,
+            Direction::DOWN => Direction::LEFT,
+            Direction::RIGHT => Direction::UP,
+            Direction::LEFT => Direction::DOWN,
+        };
+    }
+
+    /// The direction a beam travelling this way leaves a `\` mirror in.
+    pub fn reflect_backslash(self) -> Direction {
+        return match self {
+            Direction::UP => Direction::LEFT,
+            Direction::DOWN => Direction::RIGHT,
+            Direction::RIGHT => Direction::DOWN,
+            Direction::LEFT => Direction::UP,
+        };
+    }
+
+    /// Whether this is `LEFT` or `RIGHT`.
+    pub fn is_horizontal(self) -> bool {
+        return matches!(self, Direction::LEFT | Direction::RIGHT);
+    }
+
+    /// Whether this is `UP` or `DOWN`.
+    pub fn is_vertical(self) -> bool {
+        return !self.is_horizontal();
+    }
+}
+
+/// A signed `(x, y)` coordinate, or the vector between two of them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Coord {
+    /// The column, growing to the right.
+    pub x: i64,
+    /// The row, growing downwards.
+    pub y: i64,
+}
+
+impl Coord {
+    /// The top left corner.
+    pub const ORIGIN: Coord = Coord { x: 0, y: 0 };
+
+    /// The coordinate in column `x` and row `y`.
+    pub const fn new(x: i64, y: i64) -> Coord {
+        return Coord { x, y };
+    }
+
+    /// The number of steps between the two coordinates, moving only along the grid.
+    pub fn manhattan(self, other: Coord) -> u64 {
+        return self.x.abs_diff(other.x) + self.y.abs_diff(other.y);
+    }
+
+    /// The neighbouring coordinate in `direction`.
+    pub fn step(self, direction: Direction) -> Coord {
+        return self + direction;
+    }
+
+    /// Steps one cell in `direction`, or `None` when that leaves a `width` by `height` area at the origin.
+    pub fn step_within(self, direction: Direction, width: usize, height: usize) -> Option<Coord> {
+        let next = self.step(direction);
+        return if next.is_within(width, height) { Some(next) } else { None };
+    }
+
+    /// Whether this is inside a `width` by `height` area at the origin.
+    pub fn is_within(self, width: usize, height: usize) -> bool {
+        return 0 <= self.x && self.x < width as i64 && 0 <= self.y && self.y < height as i64;
+    }
+}
+
+impl From<Direction> for Coord {
+    fn from(direction: Direction) -> Coord {
+        return match direction {
+            Direction::UP => Coord::new(0, -1),
+            Direction::DOWN => Coord::new(0, 1),
+            Direction::LEFT => Coord::new(-1, 0),
+            Direction::RIGHT => Coord::new(1, 0),
+        };
+    }
+}
+
+impl From<Position> for Coord {
+    fn from((x, y): Position) -> Coord {
+        return Coord::new(x as i64, y as i64);
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Coord) -> Coord {
+        return Coord::new(self.x + rhs.x, self.y + rhs.y);
+    }
+}
+
+impl Add<Direction> for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Direction) -> Coord {
+        return self + Coord::from(rhs);
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Direction> for Coord {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Coord) -> Coord {
+        return Coord::new(self.x - rhs.x, self.y - rhs.y);
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, rhs: Coord) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        return Coord::new(-self.x, -self.y);
+    }
+}
+
+impl Mul<i64> for Coord {
+    type Output = Coord;
+
+    fn mul(self, rhs: i64) -> Coord {
+        return Coord::new(self.x * rhs, self.y * rhs);
+    }
+}
+
+impl Mul<i64> for Direction {
+    type Output = Coord;
+
+    fn mul(self, rhs: i64) -> Coord {
+        return Coord::from(self) * rhs;
+    }
+}
+
+impl MulAssign<i64> for Coord {
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        assert!(coord.x >= 0 && coord.y >= 0, "{coord:?} is outside the grid");
+        return &self[(coord.x as usize, coord.y as usize)];
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        assert!(coord.x >= 0 && coord.y >= 0, "{coord:?} is outside the grid");
+        return &mut self[(coord.x as usize, coord.y as usize)];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_are_quarter_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_ne!(direction.is_horizontal(), direction.turn_left().is_horizontal());
+        }
+        assert_eq!(Direction::UP.turn_left(), Direction::LEFT);
+        assert_eq!(Direction::UP.turn_right(), Direction::RIGHT);
+    }
+
+    #[test]
+    fn mirrors_reflect_beams() {
+        assert_eq!(Direction::RIGHT.reflect_slash(), Direction::UP);
+        assert_eq!(Direction::RIGHT.reflect_backslash(), Direction::DOWN);
+        for direction in Direction::ALL {
+            assert_eq!(direction.reflect_slash().reflect_slash(), direction);
+            assert_eq!(direction.reflect_backslash().reflect_backslash(), direction);
+        }
+    }
+
+    #[test]
+    fn coords_support_vector_arithmetic() {
+        let mut coord = Coord::new(2, 3);
+        assert_eq!(coord + Coord::new(1, -1), Coord::new(3, 2));
+        assert_eq!(coord - Coord::new(1, -1), Coord::new(1, 4));
+        assert_eq!(-coord, Coord::new(-2, -3));
+        assert_eq!(coord * 3, Coord::new(6, 9));
+        assert_eq!(Direction::LEFT * 4, Coord::new(-4, 0));
+
+        coord += Direction::UP;
+        coord -= Coord::new(2, 0);
+        coord *= 2;
+        assert_eq!(coord, Coord::new(0, 4));
+    }
+
+    #[test]
+    fn measures_manhattan_distance() {
+        assert_eq!(Coord::new(1, 6).manhattan(Coord::new(5, 11)), 9);
+        assert_eq!(Coord::new(-3, 2).manhattan(Coord::ORIGIN), 5);
+    }
+
+    #[test]
+    fn checked_steps_stay_inside_bounds() {
+        assert_eq!(Coord::ORIGIN.step_within(Direction::RIGHT, 2, 2), Some(Coord::new(1, 0)));
+        assert_eq!(Coord::ORIGIN.step_within(Direction::UP, 2, 2), None);
+        assert_eq!(Coord::new(1, 1).step_within(Direction::DOWN, 2, 2), None);
+        assert_eq!(Coord::new(1, 1).step(Direction::DOWN), Coord::new(1, 2));
+    }
+
+    #[test]
+    fn indexes_grids() {
+        let mut grid = Grid::filled(2, 2, 0);
+        grid[Coord::new(1, 0)] = 7;
+        assert_eq!(grid[(1, 0)], 7);
+        assert_eq!(grid[Coord::from((1, 0))], 7);
+    }
+}
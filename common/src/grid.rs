@@ -0,0 +1,306 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::{Lines, ParseError};
+
+/// A `(x, y)` position in a grid, with `(0, 0)` in the top left corner.
+pub type Position = (usize, usize);
+
+/// A cell type that is read from, and written back as, a single character of a grid.
+pub trait Cell: Sized {
+    /// The accepted characters, as shown in parse errors.
+    const EXPECTED: &'static str;
+
+    /// The cell written as `c`, or `None` if `c` is not one of the [`EXPECTED`](Cell::EXPECTED) characters.
+    fn from_char(c: char) -> Option<Self>;
+    /// The character the cell is written as.
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    const EXPECTED: &'static str = "a grid cell";
+
+    fn from_char(c: char) -> Option<char> {
+        return Some(c);
+    }
+
+    fn to_char(&self) -> char {
+        return *self;
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row order. Panics if there are not `width * height` of them.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "a {width}x{height} grid needs {} cells", width * height);
+        return Grid { width, height, cells };
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Whether `position` is inside the grid.
+    pub fn contains(&self, (x, y): Position) -> bool {
+        return x < self.width && y < self.height;
+    }
+
+    /// The cell at `position`, or `None` outside the grid.
+    pub fn get(&self, position: Position) -> Option<&T> {
+        return if self.contains(position) { Some(&self[position]) } else { None };
+    }
+
+    /// The cell at `position` to change, or `None` outside the grid.
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        return if self.contains(position) { Some(&mut self[position]) } else { None };
+    }
+
+    /// The up to four positions orthogonally adjacent to `position`, in up, down, left, right order.
+    pub fn neighbours4(&self, (x, y): Position) -> impl Iterator<Item=Position> + '_ {
+        return [(0, -1), (0, 1), (-1, 0), (1, 0)]
+            .into_iter()
+            .filter_map(move |offset| self.offset((x, y), offset));
+    }
+
+    /// The up to eight positions orthogonally or diagonally adjacent to `position`.
+    pub fn neighbours8(&self, (x, y): Position) -> impl Iterator<Item=Position> + '_ {
+        return [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)]
+            .into_iter()
+            .filter_map(move |offset| self.offset((x, y), offset));
+    }
+
+    fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        return if self.contains(position) { Some(position) } else { None };
+    }
+
+    /// The cells of row `y`, from left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    /// Every row, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item=&[T]> {
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item=&T> {
+        assert!(x < self.width, "column {x} is outside a grid of width {}", self.width);
+        return self.cells[x..].iter().step_by(self.width);
+    }
+
+    /// Every column, from left to right.
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item=impl DoubleEndedIterator<Item=&T>> {
+        return (0..self.width).map(|x| self.column(x));
+    }
+
+    /// Every position in row order.
+    pub fn positions(&self) -> impl Iterator<Item=Position> {
+        let width = self.width;
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+    }
+
+    /// Every cell together with its position, in row order.
+    pub fn iter(&self) -> impl Iterator<Item=(Position, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() };
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        return Grid { width, height, cells: vec![value; width * height] };
+    }
+
+    /// Mirrors the grid across its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = self.columns().flatten().cloned().collect();
+        return Grid { width: self.height, height: self.width, cells };
+    }
+
+    /// Turns the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = self.columns().flat_map(|column| column.rev()).cloned().collect();
+        return Grid { width: self.height, height: self.width, cells };
+    }
+
+    /// Turns the grid a quarter turn counterclockwise, so the right column becomes the top row.
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        let cells = self.columns().rev().flatten().cloned().collect();
+        return Grid { width: self.height, height: self.width, cells };
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    /// Reads a grid with one row per line, rejecting unknown characters and rows of different lengths. Line endings
+    /// and trailing blank lines are tidied up as by [`Lines::new`].
+    pub fn parse(input: &str) -> Result<Grid<T>, ParseError> {
+        return Lines::new(input).grid();
+    }
+
+    /// Like [`Grid::parse`], for a grid that starts on line number `first_line` of the input.
+    pub fn parse_lines(lines: &[&str], first_line: usize) -> Result<Grid<T>, ParseError> {
+        let width = match lines.first() {
+            Some(line) if !line.is_empty() => line.chars().count(),
+            Some(line) => return Err(ParseError::end_of_line(first_line, line, T::EXPECTED)),
+            None => return Err(ParseError::end_of_input(first_line - 1, T::EXPECTED)),
+        };
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let number = first_line + i;
+            let mut row_width = 0;
+            for (offset, c) in line.char_indices() {
+                let token = &line[offset..offset + c.len_utf8()];
+                if row_width == width {
+                    return Err(ParseError::at(number, line, &line[offset..], &format!("the end of a row {width} cells wide")));
+                }
+                cells.push(T::from_char(c).ok_or_else(|| ParseError::at(number, line, token, T::EXPECTED))?);
+                row_width += 1;
+            }
+            if row_width < width {
+                return Err(ParseError::end_of_line(number, line, &format!("a row {width} cells wide")));
+            }
+        }
+        return Ok(Grid { width, height: lines.len(), cells });
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Position) -> &T {
+        assert!(x < self.width && y < self.height, "({x}, {y}) is outside a {}x{} grid", self.width, self.height);
+        return &self.cells[y * self.width + x];
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Position) -> &mut T {
+        assert!(x < self.width && y < self.height, "({x}, {y}) is outside a {}x{} grid", self.width, self.height);
+        return &mut self.cells[y * self.width + x];
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().map(Cell::to_char).collect::<String>())?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Found;
+
+    fn sample() -> Grid<char> {
+        return Grid::parse("abc\ndef\n").unwrap();
+    }
+
+    #[test]
+    fn parses_rows_in_order() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 0)], 'a');
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let short = Grid::<char>::parse("abc\nde\n").unwrap_err();
+        assert_eq!((short.line, short.column, short.found), (2, 3, Found::EndOfLine));
+
+        let long = Grid::<char>::parse("abc\ndefg\n").unwrap_err();
+        assert_eq!((long.line, long.column, long.found), (2, 4, Found::Token("g".to_string())));
+    }
+
+    #[test]
+    fn rejects_unknown_cells() {
+        #[derive(Debug)]
+        struct Rock;
+
+        impl Cell for Rock {
+            const EXPECTED: &'static str = "`#`";
+
+            fn from_char(c: char) -> Option<Rock> {
+                return if c == '#' { Some(Rock) } else { None };
+            }
+
+            fn to_char(&self) -> char {
+                return '#';
+            }
+        }
+
+        let error = Grid::<Rock>::parse_lines(&["##", "#."], 5).unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (6, 2, "`#`"));
+        assert!(Grid::<Rock>::parse("").is_err());
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let grid = sample();
+        assert_eq!(grid.get((1, 1)), Some(&'e'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+    }
+
+    #[test]
+    fn neighbourhoods_stay_inside_the_grid() {
+        let grid = sample();
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours4((1, 1)).collect::<Vec<_>>(), [(1, 0), (0, 1), (2, 1)]);
+        assert_eq!(grid.neighbours8((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = sample();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(rows, ["abc", "def"]);
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn displays_one_row_per_line() {
+        let mut grid = sample();
+        grid[(1, 0)] = '#';
+        assert_eq!(grid.to_string(), "a#c\ndef\n");
+        assert_eq!(Grid::filled(2, 1, '.').to_string(), "..\n");
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "A#C\nDEF\n");
+    }
+}
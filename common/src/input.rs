@@ -0,0 +1,67 @@
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, File};
+use std::io::{stdin, BufRead, BufReader, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a puzzle input is read from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    /// A file at the given path.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// The whole input.
+    pub fn read(&self) -> std::io::Result<String> {
+        return match self {
+            InputSource::File(path) => read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        };
+    }
+
+    /// Opens the input to be read bit by bit, for inputs too big to hold in memory.
+    pub fn open(&self) -> std::io::Result<Box<dyn BufRead>> {
+        return match self {
+            InputSource::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+            InputSource::Stdin => Ok(Box::new(stdin().lock())),
+        };
+    }
+}
+
+impl From<&str> for InputSource {
+    /// `-` stands for stdin, anything else is a path.
+    fn from(s: &str) -> Self {
+        return if s == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(s))
+        };
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        };
+    }
+}
+
+/// Picks the inputs to solve: every argument if there are any, otherwise stdin when something is
+/// piped into it, and `default` as a last resort.
+pub fn input_sources(args: &[String], default: &Path) -> Vec<InputSource> {
+    if !args.is_empty() {
+        return args.iter().map(|arg| InputSource::from(arg.as_str())).collect();
+    }
+    if !stdin().is_terminal() {
+        return vec![InputSource::Stdin];
+    }
+    return vec![InputSource::File(default.to_path_buf())];
+}
@@ -0,0 +1,24 @@
+//! What the days share: the [`Solution`] trait and the runner around it, the parser combinators, grids and
+//! coordinates, searches, cycle detection, pictures and simulations.
+
+mod cycle;
+mod error;
+mod geometry;
+mod grid;
+mod input;
+mod parse;
+mod picture;
+mod search;
+mod simulation;
+mod solution;
+
+pub use cycle::Cycle;
+pub use error::{Found, ParseError, SolveError};
+pub use geometry::{Coord, Direction};
+pub use grid::{Cell, Grid, Position};
+pub use input::{input_sources, InputSource};
+pub use parse::{Line, Lines};
+pub use picture::{Colour, Picture, Point};
+pub use search::{a_star, dijkstra, Path};
+pub use simulation::Simulation;
+pub use solution::{print_answers, print_star, run, run_with, Solution};
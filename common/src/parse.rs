@@ -0,0 +1,389 @@
+//! Combinators for reading puzzle inputs, which fail with a [`ParseError`] pointing at the token that did not fit.
+//!
+//! [`Lines`] hands out numbered lines and splits an input into blank-line-separated blocks. A [`Line`] is read from
+//! left to right, and every read skips the spaces before its token.
+//!
+//! Inputs saved on other machines are read the same as the originals: a byte order mark, `\r\n` line endings,
+//! spaces at the end of lines and blank lines at the end of the input are all left out. Line numbers still match
+//! the file.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use crate::{Cell, Grid, ParseError};
+
+/// The lines of an input, or of one block of it, read from top to bottom.
+#[derive(Clone, Debug)]
+pub struct Lines<'a> {
+    lines: Vec<&'a str>,
+    /// The number of `lines[0]` in the whole input.
+    first_line: usize,
+    next: usize,
+}
+
+impl<'a> Lines<'a> {
+    /// The lines of `input`, without a byte order mark, trailing whitespace or trailing blank lines.
+    pub fn new(input: &'a str) -> Lines<'a> {
+        let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        let mut lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        return Lines { lines, first_line: 1, next: 0 };
+    }
+
+    /// The number of the next line in the whole input.
+    pub fn line_number(&self) -> usize {
+        return self.first_line + self.next;
+    }
+
+    /// Whether every line has been read.
+    pub fn is_done(&self) -> bool {
+        return self.next == self.lines.len();
+    }
+
+    /// Takes the next line, which should hold `expected`.
+    pub fn next_line(&mut self, expected: &str) -> Result<Line<'a>, ParseError> {
+        let text = self.lines.get(self.next).ok_or_else(|| ParseError::end_of_input(self.line_number() - 1, expected))?;
+        let line = Line::new(self.line_number(), text);
+        self.next += 1;
+        return Ok(line);
+    }
+
+    /// Reads the whole of the next line with `read`.
+    pub fn line<T>(&mut self, expected: &str, read: impl FnOnce(&mut Line<'a>) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let mut line = self.next_line(expected)?;
+        let value = read(&mut line)?;
+        line.end()?;
+        return Ok(value);
+    }
+
+    /// Reads the whole of the next line, which starts with `label`, with `read`.
+    pub fn labeled<T>(&mut self, label: &str, read: impl FnOnce(&mut Line<'a>) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        return self.line(&format!("a `{label}` line"), |line| {
+            line.literal(label)?;
+            return read(line);
+        });
+    }
+
+    /// Reads the whole of every line left with `read`.
+    pub fn each<T>(&mut self, mut read: impl FnMut(&mut Line<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut values = Vec::with_capacity(self.lines.len() - self.next);
+        while !self.is_done() {
+            values.push(self.line("", &mut read)?);
+        }
+        return Ok(values);
+    }
+
+    /// Reads every line left as the rows of a grid.
+    pub fn grid<T: Cell>(&mut self) -> Result<Grid<T>, ParseError> {
+        let grid = Grid::parse_lines(&self.lines[self.next..], self.line_number())?;
+        self.next = self.lines.len();
+        return Ok(grid);
+    }
+
+    /// Splits the lines left into blocks separated by one or more blank lines. There must be at least one block.
+    pub fn blocks(&mut self) -> Result<Vec<Lines<'a>>, ParseError> {
+        let mut blocks = vec![];
+        let mut start = self.next;
+        for i in self.next..=self.lines.len() {
+            if i < self.lines.len() && !self.lines[i].is_empty() {
+                continue;
+            }
+            if i > start {
+                blocks.push(Lines { lines: self.lines[start..i].to_vec(), first_line: self.first_line + start, next: 0 });
+            }
+            start = i + 1;
+        }
+        if blocks.is_empty() {
+            return Err(ParseError::end_of_input(self.first_line + self.lines.len() - 1, "a line that is not blank"));
+        }
+        self.next = self.lines.len();
+        return Ok(blocks);
+    }
+
+    /// Like [`Lines::blocks`], for exactly `N` blocks, where `expected` describes each of them.
+    pub fn split_blocks<const N: usize>(&mut self, expected: [&str; N]) -> Result<[Lines<'a>; N], ParseError> {
+        let last_line = self.first_line + self.lines.len() - 1;
+        let blocks = self.blocks()?;
+        if let Some(extra) = blocks.get(N) {
+            extra.end()?;
+        }
+        if let Some(missing) = expected.get(blocks.len()) {
+            return Err(ParseError::end_of_input(last_line, missing));
+        }
+        return Ok(blocks.try_into().unwrap());
+    }
+
+    /// Checks that no lines are left.
+    pub fn end(&self) -> Result<(), ParseError> {
+        return match self.lines.get(self.next) {
+            Some(text) => Err(Line::new(self.line_number(), text).unexpected("no more lines")),
+            None => Ok(()),
+        };
+    }
+}
+
+/// One line of an input, read from left to right.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    /// The line's number in the whole input, counting from 1.
+    pub number: usize,
+    /// The whole line.
+    pub text: &'a str,
+    /// The part of `text` not read yet.
+    rest: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Line `number` of the input, with nothing read yet.
+    pub fn new(number: usize, text: &'a str) -> Line<'a> {
+        return Line { number, text, rest: text };
+    }
+
+    /// An error for `token`, which must be a slice of this line.
+    pub fn error(&self, token: &str, expected: &str) -> ParseError {
+        return ParseError::at(self.number, self.text, token, expected);
+    }
+
+    /// An error for whatever comes next: the next word, or the end of the line.
+    pub fn unexpected(&self, expected: &str) -> ParseError {
+        return match self.rest.split_whitespace().next() {
+            Some(word) => self.error(word, expected),
+            None => ParseError::end_of_line(self.number, self.text, expected),
+        };
+    }
+
+    fn skip_spaces(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// Whether only spaces are left.
+    pub fn is_done(&self) -> bool {
+        return self.rest.trim_start().is_empty();
+    }
+
+    /// Checks that only spaces are left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        return if self.is_done() { Ok(()) } else { Err(self.unexpected("the end of the line")) };
+    }
+
+    /// Reads everything left, spaces included.
+    pub fn rest(&mut self) -> &'a str {
+        let rest = self.rest;
+        self.rest = &rest[rest.len()..];
+        return rest;
+    }
+
+    /// Reads `literal` if it comes next, and returns whether it did.
+    pub fn optional(&mut self, literal: &str) -> bool {
+        self.skip_spaces();
+        return match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        };
+    }
+
+    /// Reads exactly `literal`.
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        return if self.optional(literal) { Ok(()) } else { Err(self.unexpected(&format!("`{literal}`"))) };
+    }
+
+    /// Reads the longest run of characters that `accept`, which must not be empty.
+    pub fn take_while(&mut self, accept: impl Fn(char) -> bool, expected: &str) -> Result<&'a str, ParseError> {
+        self.skip_spaces();
+        let end = self.rest.find(|c: char| !accept(c)).unwrap_or(self.rest.len());
+        if end == 0 {
+            return Err(self.unexpected(expected));
+        }
+        let (token, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        return Ok(token);
+    }
+
+    /// Reads a word, up to the next space.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        return self.take_while(|c| !c.is_whitespace(), expected);
+    }
+
+    /// Reads a word made only of characters that `accept`, failing on the whole word otherwise.
+    pub fn word_of(&mut self, accept: impl Fn(char) -> bool, expected: &str) -> Result<&'a str, ParseError> {
+        let word = self.word(expected)?;
+        return if word.chars().all(accept) { Ok(word) } else { Err(self.error(word, expected)) };
+    }
+
+    /// Reads a name made of letters, digits and underscores.
+    pub fn name(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        return self.take_while(|c| c.is_alphanumeric() || c == '_', expected);
+    }
+
+    /// Reads a decimal integer with an optional sign, which must not run on into a name like `12ab`. Unsigned
+    /// types reject a `-` sign, so counts and sizes should be read as one.
+    pub fn integer<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        self.skip_spaces();
+        let sign = if self.rest.starts_with(['-', '+']) { 1 } else { 0 };
+        let digits = self.rest[sign..].find(|c: char| !c.is_ascii_digit()).unwrap_or(self.rest.len() - sign);
+        let runs_on = self.rest[sign + digits..].starts_with(|c: char| c.is_alphanumeric() || c == '_');
+        if digits == 0 || runs_on {
+            return Err(self.unexpected(expected));
+        }
+        let (token, rest) = self.rest.split_at(sign + digits);
+        let value = token.parse().map_err(|_| self.error(token, expected))?;
+        self.rest = rest;
+        return Ok(value);
+    }
+
+    /// Reads one of `choices`, each a literal and the value it stands for, trying them in order.
+    pub fn one_of<T: Clone>(&mut self, choices: &[(&str, T)], expected: &str) -> Result<T, ParseError> {
+        for (literal, value) in choices {
+            if self.optional(literal) {
+                return Ok(value.clone());
+            }
+        }
+        return Err(self.unexpected(expected));
+    }
+
+    /// Reads every character left with `read`, failing on the first one it returns `None` for.
+    pub fn chars<T>(&mut self, read: impl Fn(char) -> Option<T>, expected: &str) -> Result<Vec<T>, ParseError> {
+        self.skip_spaces();
+        if self.rest.is_empty() {
+            return Err(self.unexpected(expected));
+        }
+        let mut values = Vec::with_capacity(self.rest.len());
+        for (i, c) in self.rest.char_indices() {
+            values.push(read(c).ok_or_else(|| self.error(&self.rest[i..i + c.len_utf8()], expected))?);
+        }
+        self.rest();
+        return Ok(values);
+    }
+
+    /// Reads one or more items separated by `separator`.
+    pub fn list<T>(&mut self, separator: &str, mut item: impl FnMut(&mut Line<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.optional(separator) {
+            items.push(item(self)?);
+        }
+        return Ok(items);
+    }
+
+    /// Reads items up to the end of the line.
+    pub fn many<T>(&mut self, mut item: impl FnMut(&mut Line<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![];
+        while !self.is_done() {
+            items.push(item(self)?);
+        }
+        return Ok(items);
+    }
+
+    /// Reads items up to `terminator`, which is read too.
+    pub fn until<T>(&mut self, terminator: &str, mut item: impl FnMut(&mut Line<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![];
+        while !self.optional(terminator) {
+            if self.is_done() {
+                return Err(self.unexpected(&format!("`{terminator}`")));
+            }
+            items.push(item(self)?);
+        }
+        return Ok(items);
+    }
+
+    /// Reads `open`, then `read`, then `close`.
+    pub fn between<T>(&mut self, open: &str, close: &str, read: impl FnOnce(&mut Line<'a>) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        self.literal(open)?;
+        let value = read(self)?;
+        self.literal(close)?;
+        return Ok(value);
+    }
+
+    /// Reads a list of `name`, `assign`, value entries separated by `separator`, failing on a repeated name.
+    pub fn map<V>(&mut self, separator: &str, assign: &str, key_expected: &str,
+                  mut value: impl FnMut(&mut Line<'a>) -> Result<V, ParseError>) -> Result<HashMap<String, V>, ParseError> {
+        let mut map = HashMap::new();
+        self.list(separator, |line| {
+            let key = line.name(key_expected)?;
+            line.literal(assign)?;
+            if map.insert(key.to_string(), value(line)?).is_some() {
+                return Err(line.error(key, &format!("{key_expected} not given before")));
+            }
+            return Ok(());
+        })?;
+        return Ok(map);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Found;
+    use super::*;
+
+    #[test]
+    fn reads_tokens_in_order() {
+        let mut line = Line::new(1, "Card  3: 41 -48 | 83");
+        line.literal("Card").unwrap();
+        assert_eq!(line.integer::<u32>("a card number"), Ok(3));
+        line.literal(":").unwrap();
+        assert_eq!(line.until("|", |line| line.integer::<i64>("a number")), Ok(vec![41, -48]));
+        assert_eq!(line.many(|line| line.integer::<i64>("a number")), Ok(vec![83]));
+        assert!(line.end().is_ok());
+    }
+
+    #[test]
+    fn points_at_the_token_that_does_not_fit() {
+        let mut line = Line::new(4, "seeds: 79 1x4");
+        line.literal("seeds:").unwrap();
+        let error = line.many(|line| line.integer::<i64>("a seed number")).unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (4, 11, Found::Token("1x4".to_string())));
+
+        let error = Lines::new("Time:").labeled("Time:", |line| line.integer::<u64>("a time")).unwrap_err();
+        assert_eq!((error.column, error.found), (6, Found::EndOfLine));
+
+        let error = Line::new(1, "-3 blue").integer::<u32>("a cube count").unwrap_err();
+        assert_eq!((error.column, error.found), (1, Found::Token("-3".to_string())));
+    }
+
+    #[test]
+    fn reads_lists_and_maps() {
+        let mut line = Line::new(1, "{x=787,m=2655}");
+        let map = line.between("{", "}", |line| line.map(",", "=", "a rating", |line| line.integer::<i64>("a value"))).unwrap();
+        assert_eq!(map, HashMap::from([("x".to_string(), 787), ("m".to_string(), 2655)]));
+
+        let error = Line::new(1, "x=1,x=2").map(",", "=", "a rating", |line| line.integer::<i64>("a value")).unwrap_err();
+        assert_eq!((error.column, error.found), (5, Found::Token("x".to_string())));
+
+        let mut line = Line::new(1, "AAA = (BBB, CCC)");
+        let (key, value) = (line.name("a node").unwrap(), line.between("= (", ")", |line| line.list(",", |line| line.name("a node"))));
+        assert_eq!((key, value), ("AAA", Ok(vec!["BBB", "CCC"])));
+    }
+
+    #[test]
+    fn normalizes_line_endings_and_trailing_space() {
+        let mut lines = Lines::new("\u{feff}ab \r\n\t\r\ncd\r\n\r\n \n");
+        let blocks = lines.blocks().unwrap();
+        assert_eq!(blocks.iter().map(|block| block.lines.clone()).collect::<Vec<_>>(), [["ab"], ["cd"]]);
+        assert_eq!(blocks[1].line_number(), 3);
+
+        let error = Lines::new("\u{feff}Time: x\r\n").labeled("Time:", |line| line.integer::<u64>("a time")).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+    }
+
+    #[test]
+    fn splits_blocks_at_blank_lines() {
+        let mut lines = Lines::new("a\nb\n\nc\n");
+        let blocks = lines.blocks().unwrap();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].line_number(), 4);
+        assert!(lines.is_done());
+
+        let blocks = Lines::new("\na\n\n\nb").blocks().unwrap();
+        assert_eq!(blocks.iter().map(Lines::line_number).collect::<Vec<_>>(), [2, 5]);
+        let error = Lines::new("\n\n").blocks().unwrap_err();
+        assert_eq!((error.line, error.found), (1, Found::EndOfInput));
+
+        let error = Lines::new("a\nb").split_blocks(["a", "a blank line and then c"]).unwrap_err();
+        assert_eq!((error.line, error.found, error.expected.as_str()), (3, Found::EndOfInput, "a blank line and then c"));
+        let error = Lines::new("a\n\nb\n\nc").split_blocks(["a", "b"]).unwrap_err();
+        assert_eq!((error.line, error.found), (5, Found::Token("c".to_string())));
+    }
+}
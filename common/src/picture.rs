@@ -0,0 +1,273 @@
+use std::fmt::Write as _;
+use crate::{Coord, Grid};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// A colour given by its red, green and blue components.
+pub struct Colour {
+    /// Red.
+    pub r: u8,
+    /// Green.
+    pub g: u8,
+    /// Blue.
+    pub b: u8,
+}
+
+impl Colour {
+    /// No light at all.
+    pub const BLACK: Colour = Colour::rgb(0, 0, 0);
+    /// Every colour at full strength.
+    pub const WHITE: Colour = Colour::rgb(255, 255, 255);
+
+    /// The colour with the given components.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Colour {
+        return Colour { r, g, b };
+    }
+
+    /// Reads a colour written as `#rrggbb`, as day 18's dig plan does.
+    pub fn from_hex(hex: &str) -> Option<Colour> {
+        let hex = hex.strip_prefix('#')?;
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some(Colour::rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+
+    /// The colour `amount` of the way from this one to `other`.
+    pub fn mix(self, other: Colour, amount: f64) -> Colour {
+        let channel = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * amount).round() as u8;
+        return Colour::rgb(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b));
+    }
+
+    fn hex(self) -> String {
+        return format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b);
+    }
+}
+
+/// A point measured in cells, where the cell at `(x, y)` covers `x..x + 1` and `y..y + 1`.
+pub type Point = (f64, f64);
+
+enum Shape {
+    LINE { points: Vec<Point>, colour: Colour },
+    POLYGON { points: Vec<Point>, colour: Colour },
+}
+
+/// How wide lines are drawn, in cells.
+const LINE_WIDTH: f64 = 0.3;
+/// How much of the picture below shows through a polygon.
+const POLYGON_OPACITY: f64 = 0.4;
+
+/// A picture of a grid with one coloured square per cell, and lines and polygons drawn over the cells.
+///
+/// It can be written out as SVG, or as PNG with the `png` feature.
+pub struct Picture {
+    cells: Grid<Colour>,
+    background: Colour,
+    shapes: Vec<Shape>,
+}
+
+impl Picture {
+    /// A `width` by `height` picture of `background`.
+    pub fn new(width: usize, height: usize, background: Colour) -> Picture {
+        return Picture { cells: Grid::new(width, height, vec![background; width * height]), background, shapes: vec![] };
+    }
+
+    /// A picture of `grid`, with each cell coloured by `colour`.
+    pub fn from_grid<T>(grid: &Grid<T>, background: Colour, colour: impl Fn(&T) -> Colour) -> Picture {
+        return Picture { cells: grid.map(colour), background, shapes: vec![] };
+    }
+
+    /// The width, in cells.
+    pub fn width(&self) -> usize {
+        return self.cells.width();
+    }
+
+    /// The height, in cells.
+    pub fn height(&self) -> usize {
+        return self.cells.height();
+    }
+
+    /// The middle of the cell at `coord`.
+    pub fn centre(coord: Coord) -> Point {
+        return (coord.x as f64 + 0.5, coord.y as f64 + 0.5);
+    }
+
+    /// Colours the cell at `coord`, unless it is outside the picture.
+    pub fn fill(&mut self, coord: Coord, colour: Colour) {
+        if coord.is_within(self.width(), self.height()) {
+            self.cells[coord] = colour;
+        }
+    }
+
+    /// Draws a line through `points`, in order.
+    pub fn line(&mut self, points: Vec<Point>, colour: Colour) {
+        self.shapes.push(Shape::LINE { points, colour });
+    }
+
+    /// Draws the outline of the polygon with corners `points`, and shades its inside.
+    pub fn polygon(&mut self, points: Vec<Point>, colour: Colour) {
+        self.shapes.push(Shape::POLYGON { points, colour });
+    }
+
+    /// The picture as an SVG document, with one unit per cell.
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.width(), self.height());
+        let mut svg = String::new();
+        writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{}" height="{}" shape-rendering="crispEdges">"#,
+                 width * 8, height * 8).unwrap();
+        writeln!(svg, r#"<rect width="{width}" height="{height}" fill="{}"/>"#, self.background.hex()).unwrap();
+        for (position, colour) in self.cells.iter() {
+            if *colour != self.background {
+                writeln!(svg, r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#, position.0, position.1, colour.hex()).unwrap();
+            }
+        }
+        for shape in &self.shapes {
+            match shape {
+                Shape::LINE { points, colour } => writeln!(svg,
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{LINE_WIDTH}" stroke-linejoin="round" stroke-linecap="round" shape-rendering="auto"/>"#,
+                    svg_points(points), colour.hex()).unwrap(),
+                Shape::POLYGON { points, colour } => writeln!(svg,
+                    r#"<polygon points="{}" fill="{}" fill-opacity="{POLYGON_OPACITY}" fill-rule="evenodd" stroke="{1}" stroke-width="{LINE_WIDTH}" shape-rendering="auto"/>"#,
+                    svg_points(points), colour.hex()).unwrap(),
+            }
+        }
+        svg.push_str("</svg>\n");
+        return svg;
+    }
+
+    /// The picture as rows of pixels, `scale` pixels to a cell.
+    pub fn to_pixels(&self, scale: usize) -> Grid<Colour> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut pixels = Grid::new(width, height, vec![self.background; width * height]);
+        for ((x, y), colour) in self.cells.iter() {
+            for py in y * scale..(y + 1) * scale {
+                for px in x * scale..(x + 1) * scale {
+                    pixels[(px, py)] = *colour;
+                }
+            }
+        }
+        let scale = scale as f64;
+        for shape in &self.shapes {
+            match shape {
+                Shape::LINE { points, colour } => {
+                    for pair in points.windows(2) {
+                        draw_segment(&mut pixels, scale, pair[0], pair[1], *colour);
+                    }
+                }
+                Shape::POLYGON { points, colour } => {
+                    shade_polygon(&mut pixels, scale, points, *colour);
+                    for (i, point) in points.iter().enumerate() {
+                        draw_segment(&mut pixels, scale, *point, points[(i + 1) % points.len()], *colour);
+                    }
+                }
+            }
+        }
+        return pixels;
+    }
+
+    /// Writes the picture as a PNG, with each cell `scale` pixels wide and high.
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, writer: impl std::io::Write, scale: usize) -> Result<(), png::EncodingError> {
+        let pixels = self.to_pixels(scale);
+        let mut encoder = png::Encoder::new(writer, pixels.width() as u32, pixels.height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let data: Vec<u8> = pixels.iter().flat_map(|(_, colour)| [colour.r, colour.g, colour.b]).collect();
+        return encoder.write_header()?.write_image_data(&data);
+    }
+}
+
+fn svg_points(points: &[Point]) -> String {
+    return points.iter().map(|(x, y)| format!("{x},{y}")).collect::<Vec<_>>().join(" ");
+}
+
+/// Colours every pixel whose middle is within half a line width of the segment from `from` to `to`.
+fn draw_segment(pixels: &mut Grid<Colour>, scale: f64, from: Point, to: Point, colour: Colour) {
+    let radius = LINE_WIDTH / 2.0;
+    let pixel_range = |a: f64, b: f64, limit: usize| {
+        let low = ((a.min(b) - radius) * scale).floor().max(0.0) as usize;
+        let high = (((a.max(b) + radius) * scale).ceil().max(0.0) as usize).min(limit);
+        low..high
+    };
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length_squared = dx * dx + dy * dy;
+    for py in pixel_range(from.1, to.1, pixels.height()) {
+        for px in pixel_range(from.0, to.0, pixels.width()) {
+            let (x, y) = ((px as f64 + 0.5) / scale, (py as f64 + 0.5) / scale);
+            let along = if length_squared == 0.0 { 0.0 } else { (((x - from.0) * dx + (y - from.1) * dy) / length_squared).clamp(0.0, 1.0) };
+            let (nearest_x, nearest_y) = (from.0 + along * dx, from.1 + along * dy);
+            if (x - nearest_x).powi(2) + (y - nearest_y).powi(2) <= radius * radius {
+                pixels[(px, py)] = colour;
+            }
+        }
+    }
+}
+
+/// Mixes `colour` into every pixel whose middle is inside the polygon, one row of pixels at a time.
+fn shade_polygon(pixels: &mut Grid<Colour>, scale: f64, points: &[Point], colour: Colour) {
+    for py in 0..pixels.height() {
+        let y = (py as f64 + 0.5) / scale;
+        let mut crossings: Vec<f64> = vec![];
+        for (i, from) in points.iter().enumerate() {
+            let to = points[(i + 1) % points.len()];
+            if (from.1 <= y) != (to.1 <= y) {
+                crossings.push(from.0 + (y - from.1) / (to.1 - from.1) * (to.0 - from.0));
+            }
+        }
+        crossings.sort_by(f64::total_cmp);
+        for pair in crossings.chunks_exact(2) {
+            let first = ((pair[0] * scale - 0.5).ceil().max(0.0) as usize).min(pixels.width());
+            let last = ((pair[1] * scale - 0.5).ceil().max(0.0) as usize).min(pixels.width());
+            for px in first..last {
+                pixels[(px, py)] = pixels[(px, py)].mix(colour, POLYGON_OPACITY);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Colour = Colour::rgb(255, 0, 0);
+
+    #[test]
+    fn reads_hex_colours() {
+        assert_eq!(Colour::from_hex("#70c710"), Some(Colour::rgb(0x70, 0xc7, 0x10)));
+        assert_eq!(Colour::from_hex("70c710"), None);
+        assert_eq!(Colour::from_hex("#70c7"), None);
+    }
+
+    #[test]
+    fn scales_cells_to_pixels() {
+        let mut picture = Picture::new(2, 1, Colour::WHITE);
+        picture.fill(Coord::new(1, 0), RED);
+        picture.fill(Coord::new(5, 5), RED);
+        let pixels = picture.to_pixels(2);
+        assert_eq!((pixels.width(), pixels.height()), (4, 2));
+        assert_eq!(pixels[(1, 1)], Colour::WHITE);
+        assert_eq!(pixels[(2, 0)], RED);
+    }
+
+    #[test]
+    fn shades_inside_polygons_only() {
+        let mut picture = Picture::new(4, 4, Colour::WHITE);
+        picture.polygon(vec![(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)], Colour::BLACK);
+        let pixels = picture.to_pixels(10);
+        assert_eq!(pixels[(20, 20)], Colour::WHITE.mix(Colour::BLACK, POLYGON_OPACITY));
+        assert_eq!(pixels[(5, 20)], Colour::WHITE);
+        assert_eq!(pixels[(10, 20)], Colour::BLACK);
+    }
+
+    #[test]
+    fn writes_svg_shapes() {
+        let mut picture = Picture::new(3, 2, Colour::WHITE);
+        picture.fill(Coord::new(2, 1), RED);
+        picture.line(vec![Picture::centre(Coord::ORIGIN), Picture::centre(Coord::new(2, 0))], Colour::BLACK);
+        let svg = picture.to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(r##"<rect x="2" y="1" width="1" height="1" fill="#ff0000"/>"##));
+        assert!(svg.contains(r#"points="0.5,0.5 2.5,0.5""#));
+        assert_eq!(svg.matches("<rect").count(), 2);
+    }
+}
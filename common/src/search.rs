@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use tracing::debug;
+
+/// A cheapest route found by [`dijkstra`] or [`a_star`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<S> {
+    /// The sum of the costs of every move along the route.
+    pub cost: u64,
+    /// Every state along the route, from the start it left to the goal it reached.
+    pub states: Vec<S>,
+}
+
+impl<S> Path<S> {
+    /// The state the route left from.
+    pub fn start(&self) -> &S {
+        return self.states.first().unwrap();
+    }
+
+    /// The state the route reached.
+    pub fn goal(&self) -> &S {
+        return self.states.last().unwrap();
+    }
+}
+
+/// Finds the cheapest route from any of `starts` to a state accepted by `is_goal`.
+///
+/// `neighbours` lists the states one move away from a state, together with the cost of that move.
+pub fn dijkstra<S, I>(starts: impl IntoIterator<Item=S>,
+                      neighbours: impl FnMut(&S) -> I,
+                      is_goal: impl FnMut(&S) -> bool) -> Option<Path<S>>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item=(S, u64)> {
+    return a_star(starts, neighbours, is_goal, |_| 0);
+}
+
+/// Like [`dijkstra`], exploring states in order of their cost so far plus `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost to the nearest goal. States are
+/// reopened when a cheaper route to them turns up, so it does not need to be consistent.
+pub fn a_star<S, I>(starts: impl IntoIterator<Item=S>,
+                    mut neighbours: impl FnMut(&S) -> I,
+                    mut is_goal: impl FnMut(&S) -> bool,
+                    mut heuristic: impl FnMut(&S) -> u64) -> Option<Path<S>>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item=(S, u64)> {
+    let mut search = Search { states: vec![], ids: HashMap::new(), costs: vec![], parents: vec![], closed: vec![] };
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        let id = search.intern(start);
+        search.costs[id] = 0;
+        queue.push(Reverse((estimate, id)));
+    }
+
+    while let Some(Reverse((_, id))) = queue.pop() {
+        if search.closed[id] {
+            continue;
+        }
+        search.closed[id] = true;
+        if is_goal(&search.states[id]) {
+            debug!(cost = search.costs[id], seen = search.states.len(), "reached a goal");
+            return Some(search.path_to(id));
+        }
+        let cost = search.costs[id];
+        for (neighbour, step_cost) in neighbours(&search.states[id]) {
+            let neighbour_cost = cost + step_cost;
+            let estimate = neighbour_cost + heuristic(&neighbour);
+            let neighbour_id = search.intern(neighbour);
+            if neighbour_cost < search.costs[neighbour_id] {
+                search.costs[neighbour_id] = neighbour_cost;
+                search.parents[neighbour_id] = Some(id);
+                search.closed[neighbour_id] = false;
+                queue.push(Reverse((estimate, neighbour_id)));
+            }
+        }
+    }
+
+    debug!(seen = search.states.len(), "no goal is reachable");
+    return None;
+}
+
+/// Bookkeeping for every state seen so far, indexed by the order they were first seen in.
+struct Search<S> {
+    states: Vec<S>,
+    ids: HashMap<S, usize>,
+    costs: Vec<u64>,
+    parents: Vec<Option<usize>>,
+    closed: Vec<bool>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn intern(&mut self, state: S) -> usize {
+        return match self.ids.entry(state) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                let id = self.states.len();
+                self.states.push(entry.key().clone());
+                self.costs.push(u64::MAX);
+                self.parents.push(None);
+                self.closed.push(false);
+                *entry.insert(id)
+            }
+        };
+    }
+
+    fn path_to(&self, goal: usize) -> Path<S> {
+        let mut states = vec![];
+        let mut current = Some(goal);
+        while let Some(id) = current {
+            states.push(self.states[id].clone());
+            current = self.parents[id];
+        }
+        states.reverse();
+        return Path { cost: self.costs[goal], states };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //  a --1-- b --1-- c
+    //  |               |
+    //  5       e       1
+    //  |               |
+    //  d ------1------ goal
+    fn neighbours(state: &&'static str) -> Vec<(&'static str, u64)> {
+        return match *state {
+            "a" => vec![("b", 1), ("d", 5)],
+            "b" => vec![("a", 1), ("c", 1)],
+            "c" => vec![("b", 1), ("goal", 1)],
+            "d" => vec![("a", 5), ("goal", 1)],
+            _ => vec![],
+        };
+    }
+
+    #[test]
+    fn finds_the_cheapest_path() {
+        let path = dijkstra(["a"], neighbours, |s| *s == "goal").unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, ["a", "b", "c", "goal"]);
+    }
+
+    #[test]
+    fn picks_the_best_start_and_goal() {
+        let path = dijkstra(["a", "d"], neighbours, |s| *s == "goal").unwrap();
+        assert_eq!(path.states, ["d", "goal"]);
+        assert_eq!((path.start(), path.goal()), (&"d", &"goal"));
+
+        let path = dijkstra(["a"], neighbours, |s| ["c", "d"].contains(s)).unwrap();
+        assert_eq!((path.cost, path.goal()), (2, &"c"));
+
+        assert_eq!(dijkstra(["goal"], neighbours, |s| *s == "goal").unwrap().cost, 0);
+    }
+
+    #[test]
+    fn reports_unreachable_goals() {
+        assert_eq!(dijkstra(["a"], neighbours, |s| *s == "e"), None);
+        assert_eq!(dijkstra(Vec::<&str>::new(), neighbours, |_| true), None);
+    }
+
+    #[test]
+    fn a_star_matches_dijkstra_on_a_grid() {
+        // Walls along x == 3 except at y == 7, on a 10x10 grid
+        let neighbours = |&(x, y): &(i64, i64)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && (x != 3 || y == 7))
+                .map(|s| (s, 1 + (s.0 * s.1) as u64 % 3))
+                .collect::<Vec<_>>()
+        };
+        let goal = (9, 0);
+        let is_goal = |s: &(i64, i64)| *s == goal;
+        let manhattan = |s: &(i64, i64)| s.0.abs_diff(goal.0) + s.1.abs_diff(goal.1);
+
+        let expected = dijkstra([(0, 0)], neighbours, is_goal).unwrap();
+        let path = a_star([(0, 0)], neighbours, is_goal, manhattan).unwrap();
+        assert_eq!(path.cost, expected.cost);
+        assert!(path.states.contains(&(3, 7)));
+    }
+
+    #[test]
+    fn reopens_states_under_an_inconsistent_heuristic() {
+        // The heuristic is admissible but makes "b" look worse than it is, so "c" is first closed too dearly
+        let neighbours = |s: &char| match s {
+            's' => vec![('a', 1), ('b', 1)],
+            'a' => vec![('c', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('g', 5)],
+            _ => vec![],
+        };
+        let heuristic = |s: &char| if *s == 'b' { 6 } else { 0 };
+        let path = a_star(['s'], neighbours, |s| *s == 'g', heuristic).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states, ['s', 'b', 'c', 'g']);
+    }
+}
@@ -0,0 +1,11 @@
+/// A puzzle that plays out one step at a time, so it can be watched as it happens.
+pub trait Simulation {
+    /// Moves on by one step, or returns `false` if the simulation has come to an end.
+    fn step(&mut self) -> bool;
+
+    /// The current state, drawn as lines of text.
+    fn frame(&self) -> String;
+
+    /// A line on where the simulation is, such as what the last step did and any running totals.
+    fn status(&self) -> String;
+}
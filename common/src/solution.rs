@@ -0,0 +1,101 @@
+use std::env;
+use std::path::Path;
+use std::process::exit;
+use tracing::info_span;
+use crate::error::{ParseError, SolveError};
+use crate::input::input_sources;
+use crate::picture::Picture;
+use crate::simulation::Simulation;
+
+/// A solver for a single day of the event.
+///
+/// Every day parses its puzzle input once into `Input` and then answers both stars from it, so
+/// callers can run, time and compare all solvers in the same way.
+pub trait Solution {
+    /// The day of the event this solution belongs to.
+    const DAY: u8;
+
+    /// The parsed representation of the puzzle input shared by both parts.
+    type Input;
+
+    /// Reads the puzzle input, or points at the first place it does not make sense.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// The first star's answer, or why the input has none.
+    fn part1(input: &Self::Input) -> Result<String, SolveError>;
+
+    /// The second star's answer, or why the input has none.
+    fn part2(input: &Self::Input) -> Result<String, SolveError>;
+
+    /// A picture of the puzzle, for the days where there is something worth looking at.
+    fn render(_input: &Self::Input) -> Option<Picture> {
+        return None;
+    }
+
+    /// The puzzle as a step by step simulation, for the days that play out over time.
+    fn simulate(_input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        return None;
+    }
+}
+
+/// Prints both stars' answers, returning whether there was an answer for each.
+pub fn print_answers<S: Solution>(input: &str) -> Result<bool, ParseError> {
+    let parsed = info_span!("parse", day = S::DAY).in_scope(|| S::parse(input))?;
+    let first = print_star("First", info_span!("part1", day = S::DAY).in_scope(|| S::part1(&parsed)));
+    let second = print_star("Second", info_span!("part2", day = S::DAY).in_scope(|| S::part2(&parsed)));
+    return Ok(first && second);
+}
+
+/// Prints one star's answer, or why there is none, returning whether there was one.
+pub fn print_star(star: &str, answer: Result<String, SolveError>) -> bool {
+    return match answer {
+        Ok(answer) => {
+            println!("{star} star: {answer}");
+            true
+        }
+        Err(e) => {
+            println!("{star} star: none, {e}");
+            false
+        }
+    };
+}
+
+/// Entry point shared by every day's binary.
+///
+/// Solves each input path given on the command line (`-` reads stdin), falling back to piped stdin
+/// and then to `input.txt`. Answers are labelled with their input when there is more than one, and
+/// inputs that fail to parse or have no answer for a star are reported without stopping the remaining ones.
+pub fn run<S: Solution>() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    run_with(&args, print_answers::<S>);
+}
+
+/// Like [`run`], for a binary that reads its own options and leaves the input paths in `args`, printing the
+/// answers for each input with `solve`, which returns whether it found all of them.
+pub fn run_with(args: &[String], solve: impl Fn(&str) -> Result<bool, ParseError>) {
+    let sources = input_sources(args, Path::new("input.txt"));
+    let mut failed = false;
+    for source in &sources {
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Could not read {source}: {e}");
+                failed = true;
+                continue;
+            }
+        };
+        if sources.len() > 1 {
+            println!("{source}:");
+        }
+        match solve(&input) {
+            Ok(answered) => failed |= !answered,
+            Err(e) => {
+                eprintln!("{source}: {}", e.report(&input));
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
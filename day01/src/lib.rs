@@ -0,0 +1,307 @@
+//! Day 1: Trebuchet?!
+//!
+//! The input is kept as its lines, and each one is turned into a calibration value by [`calibrate`], with
+//! spelled out numbers read from a [`Dictionary`]. [`explain`] shows which tokens the value came from, and
+//! [`stream`] totals inputs too big to keep in memory.
+
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+use common::{Lines, ParseError, Solution, SolveError};
+
+/// The solver for day 1.
+pub struct Day01;
+
+/// The words the puzzle spells its digits with.
+const ENGLISH: &str = include_str!("../dictionaries/english.txt");
+
+/// Spelled out numbers and the digits they stand for, read from lines like `one = 1`.
+///
+/// A word may contain spaces and may stand for several digits, like `twenty = 20`.
+#[derive(Clone, Debug)]
+pub struct Dictionary {
+    words: Vec<(String, String)>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Whether a token is a digit or a dictionary word.
+pub enum TokenKind {
+    /// A single digit like `7`.
+    DIGIT,
+    /// A dictionary word like `seven`.
+    WORD,
+}
+
+/// A digit or dictionary word found `offset` bytes into a line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Token<'a> {
+    /// Where the token starts, in bytes from the start of the line.
+    pub offset: usize,
+    /// Whether the token is a digit or a word.
+    pub kind: TokenKind,
+    /// The text matched, either a single digit or a word.
+    pub text: &'a str,
+    /// The digits the text stands for.
+    pub digits: &'a str,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            TokenKind::DIGIT => "digit",
+            TokenKind::WORD => "word",
+        };
+        return write!(f, "`{}` ({kind}) at byte {}", self.text, self.offset);
+    }
+}
+
+/// The tokens a line's calibration value is made of.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Explanation<'a> {
+    /// The first token in the line.
+    pub first: Token<'a>,
+    /// The last token in the line, which may be the first one again.
+    pub last: Token<'a>,
+    /// The first digit of the first token followed by the last digit of the last one.
+    pub value: u32,
+}
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{} and {} give {}", self.first, self.last, self.value);
+    }
+}
+
+impl Dictionary {
+    /// A dictionary without words, so only digits count.
+    pub fn digits_only() -> Dictionary {
+        return Dictionary { words: vec![] };
+    }
+
+    /// `one` to `nine`, as in the puzzle.
+    pub fn english() -> Dictionary {
+        return Dictionary::parse(ENGLISH).expect("The English dictionary is valid");
+    }
+
+    /// Reads one `word = digits` entry per line.
+    pub fn parse(text: &str) -> Result<Dictionary, ParseError> {
+        let mut words: Vec<(String, String)> = vec![];
+        Lines::new(text).each(|line| {
+            let word = line.take_while(|c| c != '=', "a word")?.trim_end();
+            if words.iter().any(|(known, _)| known == word) {
+                return Err(line.error(word, "a word not listed before"));
+            }
+            line.literal("=")?;
+            let digits = line.word_of(|c| c.is_ascii_digit(), "the digits the word stands for")?;
+            words.push((word.to_string(), digits.to_string()));
+            return Ok(());
+        })?;
+        return Ok(Dictionary { words });
+    }
+
+    /// The token starting `offset` bytes into `line`, preferring the longest word when several start there.
+    fn token_at<'a>(&'a self, line: &'a str, offset: usize) -> Option<Token<'a>> {
+        let rest = &line[offset..];
+        if rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return Some(Token { offset, kind: TokenKind::DIGIT, text: &rest[..1], digits: &rest[..1] });
+        }
+        return self.words
+            .iter()
+            .filter(|(word, _)| rest.starts_with(word.as_str()))
+            .max_by_key(|(word, _)| word.len())
+            .map(|(word, digits)| Token { offset, kind: TokenKind::WORD, text: &rest[..word.len()], digits });
+    }
+
+    /// The tokens starting first and last in `line`, each found by scanning in from its end of the line.
+    ///
+    /// Every offset is tried, so tokens sharing letters like `eightwo` are both found. When there is only one
+    /// token it is both the first and the last.
+    pub fn first_and_last<'a>(&'a self, line: &'a str) -> Option<(Token<'a>, Token<'a>)> {
+        let offsets = line.char_indices().map(|(offset, _)| offset);
+        let first = offsets.clone().find_map(|offset| self.token_at(line, offset))?;
+        let last = offsets.rev().find_map(|offset| self.token_at(line, offset))?;
+        return Some((first, last));
+    }
+}
+
+/// The first and last tokens of `line`, where tokens are digits and the words in `dictionary`, and the number
+/// formed by the first digit of the first token and the last digit of the last one. `None` if there are no tokens.
+pub fn explain<'a>(line: &'a str, dictionary: &'a Dictionary) -> Option<Explanation<'a>> {
+    let (first, last) = dictionary.first_and_last(line)?;
+    let digit = |c: Option<char>| c.and_then(|c| c.to_digit(10)).unwrap();
+    let value = digit(first.digits.chars().next()) * 10 + digit(last.digits.chars().last());
+    return Some(Explanation { first, last, value });
+}
+
+/// The calibration value of `line`, or `None` if it has no digits or words.
+pub fn calibrate(line: &str, dictionary: &Dictionary) -> Option<u32> {
+    return explain(line, dictionary).map(|explanation| explanation.value);
+}
+
+/// The sum of every line's calibration value, or an error naming the first line without any digits or words.
+pub fn total(lines: &[String], dictionary: &Dictionary) -> Result<u64, SolveError> {
+    return lines.iter()
+        .enumerate()
+        .map(|(i, line)| {
+            calibrate(line, dictionary)
+                .map(u64::from)
+                .ok_or_else(|| nothing_to_calibrate(i + 1))
+        })
+        .sum();
+}
+
+/// Both stars' totals as [`total`] gives them, reading `reader` one line at a time so that memory use does not
+/// grow with the input.
+///
+/// Lines are tidied up as [`Lines`] does. A line without any digits or words leaves the stars it has nothing for
+/// without an answer, unless it is one of the blank lines at the end. Only failing to read `reader` is an error.
+pub fn stream(mut reader: impl BufRead, dictionary: &Dictionary) -> io::Result<[Result<u64, SolveError>; 2]> {
+    let dictionaries = [&Dictionary::digits_only(), dictionary];
+    let mut totals = [Ok(0), Ok(0)];
+    let mut buffer = String::new();
+    let mut number = 0;
+    let mut first_blank = None;
+    while reader.read_line(&mut buffer)? > 0 {
+        number += 1;
+        let line = buffer.strip_prefix('\u{feff}').filter(|_| number == 1).unwrap_or(&buffer).trim_end();
+        if line.is_empty() {
+            first_blank = first_blank.or(Some(number));
+        } else {
+            for (total, dictionary) in totals.iter_mut().zip(dictionaries) {
+                let value = match first_blank {
+                    // The blank line was not one of the ones at the end after all
+                    Some(blank) => Err(nothing_to_calibrate(blank)),
+                    None => calibrate(line, dictionary).ok_or_else(|| nothing_to_calibrate(number)),
+                };
+                if let Ok(sum) = total {
+                    *total = value.map(|value| *sum + value as u64);
+                }
+            }
+        }
+        buffer.clear();
+    }
+    return Ok(totals);
+}
+
+fn nothing_to_calibrate(number: usize) -> SolveError {
+    return SolveError::new(&format!("line {number} has nothing to calibrate with"));
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return Lines::new(input).each(|line| Ok(line.rest().to_string()));
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<String, SolveError> {
+        return total(lines, &Dictionary::digits_only()).map(|total| total.to_string());
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<String, SolveError> {
+        return total(lines, &Dictionary::english()).map(|total| total.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::Cursor;
+    use std::path::PathBuf;
+    use common::Found;
+    use super::*;
+
+    fn dictionary(name: &str) -> Dictionary {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("dictionaries/{name}.txt"));
+        return Dictionary::parse(&fs::read_to_string(path).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn finds_overlapping_words_from_both_ends() {
+        let english = Dictionary::english();
+        assert_eq!(calibrate("eightwo", &english), Some(82));
+        assert_eq!(calibrate("xtwone3four", &english), Some(24));
+        assert_eq!(calibrate("oneight", &english), Some(18));
+        assert_eq!(calibrate("a7b", &Dictionary::digits_only()), Some(77));
+    }
+
+    #[test]
+    fn lines_without_digits_or_words_have_no_value() {
+        assert_eq!(calibrate("pqrst", &Dictionary::english()), None);
+        assert_eq!(calibrate("two", &Dictionary::digits_only()), None);
+        assert_eq!(calibrate("", &Dictionary::english()), None);
+    }
+
+    #[test]
+    fn lines_without_digits_or_words_leave_their_star_without_an_answer() {
+        let lines = Day01::parse("two1nine\neightwothree\nabcone2threexyz\n").unwrap();
+        assert_eq!(Day01::part1(&lines), Err(SolveError::new("line 2 has nothing to calibrate with")));
+        assert_eq!(Day01::part2(&lines), Ok("125".to_string()));
+    }
+
+    #[test]
+    fn words_can_stand_for_several_digits() {
+        let extended = dictionary("english-extended");
+        // The longest word starting at an offset wins, so `sixteen` is not read as `six`
+        assert_eq!(calibrate("sixteen", &extended), Some(16));
+        assert_eq!(calibrate("twenty3zero", &extended), Some(20));
+        assert_eq!(calibrate("xninety", &extended), Some(90));
+        assert_eq!(calibrate("neuf3huitrois", &dictionary("french")), Some(93));
+    }
+
+    #[test]
+    fn explains_which_tokens_give_the_value() {
+        let english = Dictionary::english();
+        let explanation = explain("xtwone3four", &english).unwrap();
+        assert_eq!(explanation.first, Token { offset: 1, kind: TokenKind::WORD, text: "two", digits: "2" });
+        assert_eq!(explanation.last, Token { offset: 7, kind: TokenKind::WORD, text: "four", digits: "4" });
+        assert_eq!(explanation.to_string(), "`two` (word) at byte 1 and `four` (word) at byte 7 give 24");
+
+        let explanation = explain("ab5c", &english).unwrap();
+        assert_eq!((explanation.first, explanation.value), (explanation.last, 55));
+        assert_eq!(explanation.first.kind, TokenKind::DIGIT);
+        assert_eq!(explain("pqrst", &english), None);
+    }
+
+    #[test]
+    fn streaming_matches_reading_everything() {
+        let english = Dictionary::english();
+        let input = "two1nine\n8wothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+        let lines = Lines::new(input).each(|line| Ok(line.rest().to_string())).unwrap();
+        let expected = [total(&lines, &Dictionary::digits_only()), total(&lines, &english)];
+        assert_eq!(stream(Cursor::new(input), &english).unwrap(), expected);
+
+        let windows = format!("\u{feff}{}\r\n \t\r\n\n", input.replace('\n', "\r\n"));
+        assert_eq!(stream(Cursor::new(windows), &english).unwrap(), expected);
+
+        let input = "two1nine\neightwothree\nabcone2threexyz\n";
+        let lines = Lines::new(input).each(|line| Ok(line.rest().to_string())).unwrap();
+        let expected = [total(&lines, &Dictionary::digits_only()), total(&lines, &english)];
+        assert_eq!(stream(Cursor::new(input), &english).unwrap(), expected);
+    }
+
+    #[test]
+    fn streaming_fails_only_the_stars_without_tokens() {
+        let english = Dictionary::english();
+        let nothing = Err(SolveError::new("line 2 has nothing to calibrate with"));
+        let totals = stream(Cursor::new("1abc2\n\nnine\n"), &english).unwrap();
+        assert_eq!(totals, [nothing.clone(), nothing.clone()]);
+        let totals = stream(Cursor::new("1abc2\nnine\n3four\n"), &english).unwrap();
+        assert_eq!(totals, [nothing, Ok(145)]);
+        assert!(stream(Cursor::new(b"1abc2\n\xff\n".as_slice()), &english).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_dictionaries() {
+        let error = Dictionary::parse("one = 1\ntwo 2\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (2, 6, Found::EndOfLine));
+        let error = Dictionary::parse("one = one\n").unwrap_err();
+        assert_eq!((error.column, error.found), (7, Found::Token("one".to_string())));
+        let error = Dictionary::parse("one = 1\none = 2\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "a word not listed before"));
+        // An empty word would match at every offset of every line
+        let error = Dictionary::parse("one = 1\n  = 2\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (2, 3, Found::Token("=".to_string())));
+        assert_eq!(error.expected, "a word");
+    }
+}
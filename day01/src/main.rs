@@ -1,41 +1,108 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use clap::Parser;
+use common::{input_sources, print_star, Solution};
+use day01::{explain, stream, total, Day01, Dictionary};
 
-fn calibrate(line: &String, translate: bool) -> i32 {
-    let translator = HashMap::from([
-        ("one", "o1e"),
-        ("two", "t2o"),
-        ("three", "t3e"),
-        ("four", "4"),
-        ("five", "5e"),
-        ("six", "6"),
-        ("seven", "7n"),
-        ("eight", "e8t"),
-        ("nine", "n9e"),
-    ]);
-
-    let mut translated_line: String = String::from(line);
+#[derive(Parser)]
+#[command(about = "Solves day 1, reading spelled out numbers from a dictionary for the second star")]
+struct Cli {
+    /// File listing the words the second star reads as numbers, one `word = digits` per line.
+    /// Defaults to `one = 1` to `nine = 9`
+    #[arg(long)]
+    words: Option<PathBuf>,
+    /// For every line, show the first and last tokens each star found and the value they give. Lines without
+    /// any are flagged and left out of the totals instead of leaving their star without an answer
+    #[arg(long, conflicts_with = "stream")]
+    explain: bool,
+    /// Read the input one line at a time and answer both stars in a single pass, for inputs too big to hold in
+    /// memory
+    #[arg(long)]
+    stream: bool,
+    /// Input files to solve, `-` for stdin. Defaults to piped stdin, then `input.txt`
+    inputs: Vec<String>,
+}
 
-    if translate {
-        for (key, translation) in translator {
-            translated_line = translated_line.replace(&key, translation);
+fn main() {
+    let cli = Cli::parse();
+    let dictionary = match &cli.words {
+        None => Dictionary::english(),
+        Some(path) => {
+            let text = read_to_string(path).unwrap_or_else(|e| {
+                eprintln!("Could not read {}: {e}", path.display());
+                exit(1);
+            });
+            Dictionary::parse(&text).unwrap_or_else(|e| {
+                eprintln!("{}: {}", path.display(), e.report(&text));
+                exit(1);
+            })
         }
+    };
+    if cli.stream {
+        stream_inputs(&cli.inputs, &dictionary);
+        return;
     }
-
-    let first_digit = translated_line.chars().find(|c| c.is_digit(10)).unwrap().to_string();
-    let last_digit = translated_line.chars().rfind(|c| c.is_digit(10)).unwrap().to_string();
-
-    return [first_digit, last_digit].join("").parse().expect("Invalid line does not match pattern");
+    common::run_with(&cli.inputs, |input| {
+        let lines = Day01::parse(input)?;
+        if cli.explain {
+            explain_lines(&lines, &dictionary);
+            return Ok(true);
+        }
+        let first = print_star("First", Day01::part1(&lines));
+        let second = print_star("Second", total(&lines, &dictionary).map(|total| total.to_string()));
+        return Ok(first && second);
+    });
 }
 
-fn main() {
-    let file = File::open("input.txt").expect("No such file");
-    let lines: Vec<String> = BufReader::new(file)
-        .lines()
-        .map(|l| l.expect("Could not parse line"))
-        .collect();
+fn stream_inputs(inputs: &[String], dictionary: &Dictionary) {
+    let sources = input_sources(inputs, Path::new("input.txt"));
+    let mut failed = false;
+    for source in &sources {
+        if sources.len() > 1 {
+            println!("{source}:");
+        }
+        match source.open().and_then(|reader| stream(reader, dictionary)) {
+            Ok([first, second]) => {
+                let first = print_star("First", first.map(|total| total.to_string()));
+                let second = print_star("Second", second.map(|total| total.to_string()));
+                failed |= !(first && second);
+            }
+            Err(e) => {
+                eprintln!("{source}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
 
-    println!("First star: {}", lines.iter().map(|l| calibrate(l, false)).sum::<i32>());
-    println!("Second star: {}", lines.iter().map(|l| calibrate(l, true)).sum::<i32>());
+fn explain_lines(lines: &[String], dictionary: &Dictionary) {
+    let stars = [("First", Dictionary::digits_only()), ("Second", dictionary.clone())];
+    let mut totals = [0u64; 2];
+    let mut flagged: [Vec<String>; 2] = [vec![], vec![]];
+    for (i, line) in lines.iter().enumerate() {
+        println!("Line {}: {line}", i + 1);
+        for (star, (name, dictionary)) in stars.iter().enumerate() {
+            match explain(line, dictionary) {
+                Some(explanation) => {
+                    println!("  {name} star: {explanation}");
+                    totals[star] += explanation.value as u64;
+                }
+                None => {
+                    println!("  {name} star: no digits or words, left out");
+                    flagged[star].push((i + 1).to_string());
+                }
+            }
+        }
+    }
+    for (star, (name, _)) in stars.iter().enumerate() {
+        if flagged[star].is_empty() {
+            println!("{name} star: {}", totals[star]);
+        } else {
+            println!("{name} star: {}, leaving out line {}", totals[star], flagged[star].join(", "));
+        }
+    }
 }
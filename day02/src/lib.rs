@@ -0,0 +1,324 @@
+//! Day 2: Cube Conundrum
+//!
+//! Each [`Game`] keeps the cubes every round showed. The first star checks them against a [`Bag`], which
+//! defaults to the puzzle's but can hold any counts of any colours, and [`breaches`] says which colours broke
+//! the bag's limits and in which round. The second star multiplies together the cubes of the smallest bag each
+//! game could have been played with, over the colours of the bag.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use common::{Line, Lines, ParseError, Solution, SolveError};
+
+/// The solver for day 2.
+pub struct Day02;
+
+/// The cubes shown in one round of a game, in the order they are listed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Draw {
+    /// Each colour shown and how many cubes of it.
+    pub cubes: Vec<(String, u32)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A game's id and the rounds played in it.
+pub struct Game {
+    /// The number after `Game`.
+    pub id: u32,
+    /// The rounds, in the order they were played.
+    pub rounds: Vec<Draw>,
+}
+
+/// The most cubes of one colour shown at once during a game, and the first round, counting from 1, to show them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Maximum {
+    /// How many cubes were shown.
+    pub count: u32,
+    /// The round that first showed them, counting from 1.
+    pub round: usize,
+}
+
+/// How many cubes of each colour are in the bag. Colours not listed have no cubes in it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bag {
+    cubes: Vec<(String, u32)>,
+}
+
+/// A colour a game showed more cubes of than the bag holds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breach {
+    /// The colour shown.
+    pub colour: String,
+    /// The most cubes of it shown at once, and when.
+    pub shown: Maximum,
+    /// The cubes of that colour in the bag, `None` if the bag has none.
+    pub limit: Option<u32>,
+}
+
+impl Display for Breach {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "round {} shows {} {}", self.shown.round, self.shown.count, self.colour)?;
+        return match self.limit {
+            Some(limit) => write!(f, " but the bag holds {limit}"),
+            None => write!(f, " but the bag has no {} cubes", self.colour),
+        };
+    }
+}
+
+/// Reads cubes like `3 blue, 4 red` onto the end of `cubes`, each colour at most once.
+fn read_cubes(line: &mut Line, cubes: &mut Vec<(String, u32)>) -> Result<(), ParseError> {
+    line.list(",", |line| {
+        let count: u32 = line.integer("a cube count")?;
+        let colour = line.name("a colour after the cube count")?;
+        if cubes.iter().any(|(known, _)| known == colour) {
+            return Err(line.error(colour, "a colour not listed before"));
+        }
+        cubes.push((colour.to_string(), count));
+        return Ok(());
+    })?;
+    return Ok(());
+}
+
+fn count_of(cubes: &[(String, u32)], colour: &str) -> Option<u32> {
+    return cubes.iter().find(|(known, _)| known == colour).map(|(_, count)| *count);
+}
+
+impl Draw {
+    /// The cubes of `colour` shown, 0 if there were none.
+    pub fn count(&self, colour: &str) -> u32 {
+        return count_of(&self.cubes, colour).unwrap_or(0);
+    }
+
+    /// Whether every cube shown could have come out of `bag`.
+    pub fn fits(&self, bag: &Bag) -> bool {
+        return self.cubes.iter().all(|(colour, count)| *count <= bag.count(colour).unwrap_or(0));
+    }
+}
+
+impl Bag {
+    /// 12 red, 13 green and 14 blue cubes, as in the puzzle.
+    pub fn puzzle() -> Bag {
+        return Bag::parse("12 red, 13 green, 14 blue").expect("The puzzle's bag is valid");
+    }
+
+    /// Reads cubes written the way games show them, like `12 red, 13 green`, over one or more lines.
+    ///
+    /// There must be at least one colour, as the power of a game against a bag without any would always be 1.
+    pub fn parse(text: &str) -> Result<Bag, ParseError> {
+        let mut cubes: Vec<(String, u32)> = vec![];
+        let mut lines = Lines::new(text);
+        lines.line("a cube count", |line| read_cubes(line, &mut cubes))?;
+        lines.each(|line| read_cubes(line, &mut cubes))?;
+        return Ok(Bag { cubes });
+    }
+
+    /// The cubes of `colour` in the bag, `None` if there are none.
+    pub fn count(&self, colour: &str) -> Option<u32> {
+        return count_of(&self.cubes, colour);
+    }
+
+    /// The colours in the bag, in the order they were listed.
+    pub fn colours(&self) -> Vec<&str> {
+        return self.cubes.iter().map(|(colour, _)| colour.as_str()).collect();
+    }
+}
+
+impl Game {
+    fn parse(line: &mut Line) -> Result<Game, ParseError> {
+        line.literal("Game")?;
+        let id: u32 = line.integer("a game id")?;
+        line.literal(":")?;
+        let rounds = line.list(";", |line| {
+            let mut cubes = vec![];
+            read_cubes(line, &mut cubes)?;
+            return Ok(Draw { cubes });
+        })?;
+        return Ok(Game { id, rounds });
+    }
+
+    /// The most cubes of each colour shown at once, with the first round to show them.
+    pub fn maxima(&self) -> HashMap<String, Maximum> {
+        let mut maxima: HashMap<String, Maximum> = HashMap::new();
+        for (i, draw) in self.rounds.iter().enumerate() {
+            for (colour, count) in &draw.cubes {
+                if maxima.get(colour).is_none_or(|max| max.count < *count) {
+                    maxima.insert(colour.to_string(), Maximum { count: *count, round: i + 1 });
+                }
+            }
+        }
+        return maxima;
+    }
+
+    /// The first round, counting from 1, showing more cubes of some colour than `bag` holds.
+    pub fn first_impossible_round(&self, bag: &Bag) -> Option<usize> {
+        return self.rounds.iter().position(|draw| !draw.fits(bag)).map(|i| i + 1);
+    }
+
+    /// Every cube shown over all the rounds.
+    pub fn total_cubes(&self) -> u32 {
+        return self.rounds.iter().flat_map(|draw| &draw.cubes).map(|(_, count)| count).sum();
+    }
+
+    /// The fewest cubes of each colour the game could have been played with, in the order the colours first
+    /// show up.
+    pub fn minimum_bag(&self) -> Bag {
+        let mut cubes: Vec<(String, u32)> = vec![];
+        for (colour, count) in self.rounds.iter().flat_map(|draw| &draw.cubes) {
+            match cubes.iter_mut().find(|(known, _)| known == colour) {
+                Some((_, most)) => *most = (*most).max(*count),
+                None => cubes.push((colour.to_string(), *count)),
+            }
+        }
+        return Bag { cubes };
+    }
+
+    /// The product of the minimum bag's cubes of each of `colours`, so 0 if the game never shows one of them.
+    pub fn power(&self, colours: &[&str]) -> Result<u64, SolveError> {
+        let minimum = self.minimum_bag();
+        let mut power: u64 = 1;
+        for colour in colours {
+            power = power.checked_mul(minimum.count(colour).unwrap_or(0) as u64).ok_or_else(|| {
+                SolveError::new(&format!("the power of game {} is too large to count", self.id))
+            })?;
+        }
+        return Ok(power);
+    }
+}
+
+/// The colours a game showed more cubes of than `bag` holds, in the order the rounds showed them.
+pub fn breaches(game: &Game, bag: &Bag) -> Vec<Breach> {
+    let mut breaches: Vec<Breach> = game.maxima()
+        .into_iter()
+        .filter(|(colour, max)| max.count > bag.count(colour).unwrap_or(0))
+        .map(|(colour, max)| Breach { limit: bag.count(&colour), colour, shown: max })
+        .collect();
+    breaches.sort_by(|a, b| (a.shown.round, &a.colour).cmp(&(b.shown.round, &b.colour)));
+    return breaches;
+}
+
+/// Whether a game could have been played with the cubes in `bag`.
+pub fn is_game_possible(game: &Game, bag: &Bag) -> bool {
+    return game.first_impossible_round(bag).is_none();
+}
+
+/// The sum of the ids of the games that could have been played with the cubes in `bag`.
+pub fn possible_ids(games: &[Game], bag: &Bag) -> u32 {
+    return games.iter()
+        .filter(|game| is_game_possible(game, bag))
+        .map(|game| game.id)
+        .sum();
+}
+
+/// The sum of every game's power over `colours`.
+pub fn total_power(games: &[Game], colours: &[&str]) -> Result<u64, SolveError> {
+    let mut total: u64 = 0;
+    for game in games {
+        total = total.checked_add(game.power(colours)?).ok_or_else(|| {
+            SolveError::new(&format!("the powers up to game {} are too large to add up", game.id))
+        })?;
+    }
+    return Ok(total);
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Lines::new(input).each(Game::parse);
+    }
+
+    fn part1(games: &Self::Input) -> Result<String, SolveError> {
+        return Ok(possible_ids(games, &Bag::puzzle()).to_string());
+    }
+
+    fn part2(games: &Self::Input) -> Result<String, SolveError> {
+        return Ok(total_power(games, &Bag::puzzle().colours())?.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use common::Found;
+    use super::*;
+
+    fn example() -> Vec<Game> {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../fixtures/day02/example.txt");
+        return Day02::parse(&fs::read_to_string(path).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn checks_games_against_any_bag() {
+        let games = example();
+        assert_eq!(possible_ids(&games, &Bag::puzzle()), 8);
+        assert_eq!(possible_ids(&games, &Bag::parse("20 red, 13 green, 14 blue").unwrap()), 11);
+        assert_eq!(possible_ids(&games, &Bag::parse("20 red\n13 green, 15 blue\n").unwrap()), 15);
+        // Colours the bag leaves out have no cubes, so every game needs some blue
+        assert_eq!(possible_ids(&games, &Bag::parse("20 red, 13 green, 1 yellow").unwrap()), 0);
+    }
+
+    #[test]
+    fn reports_the_colours_and_rounds_that_broke_the_bag() {
+        let games = example();
+        let bag = Bag::puzzle();
+        assert_eq!(breaches(&games[2], &bag), vec![
+            Breach { colour: "red".to_string(), shown: Maximum { count: 20, round: 1 }, limit: Some(12) },
+        ]);
+        let reasons: Vec<String> = breaches(&games[3], &bag).iter().map(|breach| breach.to_string()).collect();
+        assert_eq!(reasons, ["round 3 shows 15 blue but the bag holds 14", "round 3 shows 14 red but the bag holds 12"]);
+
+        let bag = Bag::parse("20 red, 20 green").unwrap();
+        assert_eq!(breaches(&games[0], &bag)[0].to_string(), "round 2 shows 6 blue but the bag has no blue cubes");
+    }
+
+    #[test]
+    fn keeps_every_round() {
+        let games = example();
+        assert_eq!(games[1].rounds[1], Draw { cubes: vec![
+            ("green".to_string(), 3), ("blue".to_string(), 4), ("red".to_string(), 1),
+        ] });
+        assert_eq!(games[2].first_impossible_round(&Bag::puzzle()), Some(1));
+        assert_eq!(games[3].first_impossible_round(&Bag::puzzle()), Some(3));
+        assert_eq!(games[0].first_impossible_round(&Bag::puzzle()), None);
+        assert_eq!(games[0].total_cubes(), 18);
+        assert_eq!(games[0].minimum_bag(), Bag::parse("6 blue, 4 red, 2 green").unwrap());
+    }
+
+    #[test]
+    fn power_covers_every_colour_of_the_bag() {
+        let games = Day02::parse("Game 1: 3 blue, 4 red; 2 green\nGame 2: 5 red, 2 blue\n").unwrap();
+        assert_eq!(games[0].power(&["red", "green", "blue"]), Ok(24));
+        // Game 2 never shows green, so it could be played without any
+        assert_eq!(games[1].power(&["red", "green", "blue"]), Ok(0));
+        assert_eq!(total_power(&games, &["red", "blue"]), Ok(12 + 10));
+        assert_eq!(total_power(&example(), &Bag::puzzle().colours()), Ok(2286));
+    }
+
+    #[test]
+    fn reports_powers_too_large_to_count() {
+        let games = Day02::parse("Game 7: 4294967295 red, 4294967295 green, 2 blue\n").unwrap();
+        assert_eq!(games[0].power(&["red", "green"]), Ok(18446744065119617025));
+        assert_eq!(games[0].power(&["red", "green", "blue"]), Err(SolveError::new("the power of game 7 is too large to count")));
+        let games = Day02::parse("Game 1: 4294967295 red, 4294967295 green\nGame 2: 4294967295 red, 4294967295 green\n").unwrap();
+        assert_eq!(total_power(&games, &["red", "green"]), Err(SolveError::new("the powers up to game 2 are too large to add up")));
+    }
+
+    #[test]
+    fn rejects_malformed_bags() {
+        let error = Bag::parse("12 red, 13 red").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (12, "a colour not listed before"));
+        let error = Bag::parse("").unwrap_err();
+        assert_eq!((error.found, error.expected.as_str()), (Found::EndOfInput, "a cube count"));
+        let error = Bag::parse("12 red, -13 green").unwrap_err();
+        assert_eq!((error.column, error.found), (9, Found::Token("-13".to_string())));
+        let error = Bag::parse("12 red 13 green").unwrap_err();
+        assert_eq!((error.column, error.found), (8, Found::Token("13".to_string())));
+        let error = Day02::parse("Game 1: -3 blue\n").unwrap_err();
+        assert_eq!((error.column, error.found), (9, Found::Token("-3".to_string())));
+        let error = Day02::parse("Game -1: 3 blue\n").unwrap_err();
+        assert_eq!((error.column, error.found), (6, Found::Token("-1".to_string())));
+        let error = Day02::parse("Game 1: 3 blue, 4 red; 2 green, 1 green\n").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (35, "a colour not listed before"));
+    }
+}
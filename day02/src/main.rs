@@ -1,69 +1,60 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::borrow::Borrow;
+use std::fs::read_to_string;
+use std::path::PathBuf;
+use std::process::exit;
+use clap::Parser;
+use common::{print_star, Solution};
+use day02::{breaches, possible_ids, total_power, Bag, Day02};
 
-fn get_max_values_for_game(line: &String) -> (i32, HashMap<String, i32>) {
-    let mut words = line.split_whitespace().into_iter().peekable();
-    assert_eq!(words.next(), Some("Game"));
-    let id: i32 = words.next().unwrap()
-        .strip_suffix(":").expect("Missing colon after game id")
-        .parse().expect("Failed to parse id");
-    let mut parsed_line: HashMap<String, i32> = HashMap::new();
-
-    while words.peek().is_some() {
-        let count: i32 = words
-            .next().unwrap()
-            .parse().expect("Failed to parse count");
-        let color = words
-            .next().expect("Missing color after count")
-            .trim_end_matches(|c| c == ';' || c == ',');
-        let current_max_value = parsed_line.get(color).or(Some(0_i32.borrow())).unwrap();
-        if current_max_value < &count {
-            parsed_line.insert(String::from(color), count);
-        }
-    }
-
-    return (id, parsed_line);
+#[derive(Parser)]
+#[command(about = "Solves day 2, checking the games against a bag of cubes, whose colours the second star multiplies")]
+struct Cli {
+    /// The cubes in the bag, written like a round, e.g. `12 red, 13 green, 14 blue, 3 yellow`. Colours left out
+    /// have no cubes, and are not part of the second star's power. Defaults to the puzzle's bag
+    #[arg(long, conflicts_with = "bag_file")]
+    bag: Option<String>,
+    /// File holding the cubes in the bag, written as for `--bag` over one or more lines
+    #[arg(long)]
+    bag_file: Option<PathBuf>,
+    /// List the games that could not have been played with the bag, with each colour that broke it and the first
+    /// round to show the most cubes of that colour
+    #[arg(long)]
+    report: bool,
+    /// Input files to solve, `-` for stdin. Defaults to piped stdin, then `input.txt`
+    inputs: Vec<String>,
 }
 
-fn is_game_possible(game: &HashMap<String, i32>) -> bool {
-    let expected_max_values = HashMap::from([
-        (String::from("red"), 12),
-        (String::from("green"), 13),
-        (String::from("blue"), 14)
-    ]);
-
-    return game.iter().all(|(color, count)| {
-        let max_value = *expected_max_values.get(color).or(Some(0_i32.borrow())).unwrap();
-        count <= &max_value
+fn main() {
+    let cli = Cli::parse();
+    let bag = match (&cli.bag, &cli.bag_file) {
+        (Some(bag), _) => read_bag("--bag", bag),
+        (None, Some(path)) => {
+            let text = read_to_string(path).unwrap_or_else(|e| {
+                eprintln!("Could not read {}: {e}", path.display());
+                exit(1);
+            });
+            read_bag(&path.display().to_string(), &text)
+        }
+        (None, None) => Bag::puzzle(),
+    };
+    common::run_with(&cli.inputs, |input| {
+        let games = Day02::parse(input)?;
+        if cli.report {
+            for game in &games {
+                let breaches = breaches(game, &bag);
+                if !breaches.is_empty() {
+                    let reasons: Vec<String> = breaches.iter().map(|breach| breach.to_string()).collect();
+                    println!("Game {}: {}", game.id, reasons.join(", "));
+                }
+            }
+        }
+        println!("First star: {}", possible_ids(&games, &bag));
+        return Ok(print_star("Second", total_power(&games, &bag.colours()).map(|power| power.to_string())));
     });
 }
 
-fn main() {
-    let file = File::open("input.txt").expect("File not found");
-    let lines: Vec<String> = BufReader::new(file)
-        .lines()
-        .map(|l| l.expect("Error parsing line"))
-        .collect();
-
-    let id_and_max_values_for_games: Vec<(i32, HashMap<String, i32>)> =
-        lines.iter()
-            .map(get_max_values_for_game)
-            .collect();
-    println!("First star: {}",
-             id_and_max_values_for_games
-                 .iter()
-                 .filter(|(_, max_values)| is_game_possible(max_values))
-                 .map(|(id, _)| id)
-                 .sum::<i32>());
-    println!("Second star: {}",
-    id_and_max_values_for_games
-        .iter()
-        .map(|(_, max_values)|
-            max_values.values().map(|value| *value)
-            .reduce(|acc, count| acc * count).or(Some(0)).unwrap()
-        )
-        .sum::<i32>()
-    );
+fn read_bag(name: &str, text: &str) -> Bag {
+    return Bag::parse(text).unwrap_or_else(|e| {
+        eprintln!("{name}: {}", e.report(text));
+        exit(1);
+    });
 }
@@ -0,0 +1,102 @@
+//! Day 3: Gear Ratios
+
+use std::collections::{HashMap, HashSet};
+use common::{Cell, Grid, ParseError, Position, Solution, SolveError};
+
+/// The solver for day 3.
+pub struct Day03;
+
+/// One character of the engine schematic.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Schematic {
+    /// A digit of a part number.
+    DIGIT(i64),
+    /// A `.`.
+    EMPTY,
+    /// A `*`, which may be a gear.
+    GEAR,
+    /// Any other symbol.
+    SYMBOL(char),
+}
+
+impl Cell for Schematic {
+    const EXPECTED: &'static str = "a digit, `.` or a symbol";
+
+    fn from_char(c: char) -> Option<Schematic> {
+        return Some(match c {
+            '0'..='9' => Schematic::DIGIT(c.to_digit(10).unwrap() as i64),
+            '.' => Schematic::EMPTY,
+            '*' => Schematic::GEAR,
+            _ => Schematic::SYMBOL(c),
+        });
+    }
+
+    fn to_char(&self) -> char {
+        return match self {
+            Schematic::DIGIT(digit) => char::from_digit(*digit as u32, 10).unwrap(),
+            Schematic::EMPTY => '.',
+            Schematic::GEAR => '*',
+            Schematic::SYMBOL(c) => *c,
+        };
+    }
+}
+
+/// The product of the part numbers next to a gear.
+pub fn gear_ratio(s: &[i64]) -> i64 {
+    return s.iter().copied().reduce(|acc, i| acc * i).unwrap();
+}
+
+/// The sum of every part number, that is every number next to a symbol, and the part numbers next to each gear.
+pub fn find_parts(schematic: &Grid<Schematic>) -> (i64, HashMap<Position, Vec<i64>>) {
+    let mut sum = 0;
+    let mut gear_adjacent_parts: HashMap<Position, Vec<i64>> = HashMap::new();
+
+    for y in 0..schematic.height() {
+        let mut current_number = 0;
+        let mut checked_positions: HashSet<Position> = HashSet::new();
+
+        // One step past the end of the row, so a number touching the right edge is still counted
+        for x in 0..=schematic.width() {
+            if let Some(Schematic::DIGIT(digit)) = schematic.get((x, y)) {
+                current_number = current_number * 10 + digit;
+                checked_positions.extend(schematic.neighbours8((x, y)));
+                continue;
+            }
+
+            let is_number_valid = checked_positions
+                .iter()
+                .any(|position| matches!(schematic[*position], Schematic::GEAR | Schematic::SYMBOL(_)));
+            if is_number_valid && current_number > 0 {
+                sum += current_number;
+                for position in &checked_positions {
+                    if schematic[*position] == Schematic::GEAR {
+                        gear_adjacent_parts.entry(*position).or_default().push(current_number);
+                    }
+                }
+            }
+            current_number = 0;
+            checked_positions.clear();
+        }
+    }
+
+    return (sum, gear_adjacent_parts);
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Grid<Schematic>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Grid::parse(input);
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<String, SolveError> {
+        let (sum, _) = find_parts(schematic);
+        return Ok(sum.to_string());
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<String, SolveError> {
+        let (_, gear_adjacent_parts) = find_parts(schematic);
+        return Ok(gear_adjacent_parts.values().filter(|v| v.len() == 2).map(|v| gear_ratio(v)).sum::<i64>().to_string());
+    }
+}
@@ -0,0 +1,126 @@
+//! Day 4: Scratchcards
+
+use std::collections::VecDeque;
+use common::{Line, Lines, ParseError, Solution, SolveError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The solver for day 4.
+pub struct Day04;
+
+/// A scratchcard. Both lists of numbers are sorted.
+pub struct Card {
+    /// The number after `Card`.
+    pub card_number: usize,
+    /// The numbers you have, after the `|`.
+    pub actual_numbers: Vec<i64>,
+    /// The winning numbers, before the `|`.
+    pub winning_numbers: Vec<i64>,
+}
+
+fn parse_line(line: &mut Line) -> Result<Card, ParseError> {
+    line.literal("Card")?;
+    let number_str = line.take_while(|c| c.is_ascii_digit(), "a card number")?;
+    let card_number = line.number;
+    if number_str.parse() != Ok(card_number) {
+        return Err(line.error(number_str, &format!("card number {card_number}")));
+    }
+    line.literal(":")?;
+    let mut winning_numbers = line.until("|", |line| line.integer::<i64>("a winning number or `|`"))?;
+    let mut actual_numbers = line.many(|line| line.integer::<i64>("a number"))?;
+    actual_numbers.sort();
+    winning_numbers.sort();
+    return Ok(Card { card_number, actual_numbers, winning_numbers });
+}
+
+/// How many winning numbers each card has, in order.
+pub fn winning_counts(cards: &[Card]) -> Vec<usize> {
+    #[cfg(feature = "parallel")]
+    return cards.par_iter().map(count_winning_numbers).collect();
+    #[cfg(not(feature = "parallel"))]
+    return cards.iter().map(count_winning_numbers).collect();
+}
+
+/// How many of the card's numbers are winning numbers.
+pub fn count_winning_numbers(card: &Card) -> usize {
+    let mut actual_index: usize = 0;
+    let mut winning_index: usize = 0;
+    let mut winning_count = 0;
+    while actual_index < card.actual_numbers.len() && winning_index < card.winning_numbers.len() {
+        while actual_index < card.actual_numbers.len() && card.actual_numbers[actual_index] < card.winning_numbers[winning_index] {
+            actual_index += 1;
+        }
+        if actual_index < card.actual_numbers.len() {
+            while winning_index < card.winning_numbers.len() && card.winning_numbers[winning_index] < card.actual_numbers[actual_index] {
+                winning_index += 1;
+            }
+        }
+        if actual_index < card.actual_numbers.len() && card.actual_numbers.get(actual_index) == card.winning_numbers.get(winning_index) {
+            winning_count += 1;
+            actual_index += 1;
+            winning_index += 1;
+        }
+    }
+    return winning_count;
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Lines::new(input).each(parse_line);
+    }
+
+    fn part1(cards: &Self::Input) -> Result<String, SolveError> {
+        let mut total: i64 = 0;
+        for (card, count) in cards.iter().zip(winning_counts(cards)) {
+            let points = if count == 0 { Some(0) } else { 2i64.checked_pow(count as u32 - 1) };
+            total = points.and_then(|points| total.checked_add(points)).ok_or_else(|| {
+                SolveError::new(&format!("the points up to card {} are too large to count", card.card_number))
+            })?;
+        }
+        return Ok(total.to_string());
+    }
+
+    fn part2(cards: &Self::Input) -> Result<String, SolveError> {
+        let counts = winning_counts(cards);
+        let mut processed_card_count = cards.len();
+        let mut cards_to_process = VecDeque::with_capacity(cards.len() * 2);
+        for card in cards {
+            cards_to_process.push_back(card);
+        }
+
+        while !cards_to_process.is_empty() {
+            let card = &cards_to_process.pop_front().unwrap();
+            // Copies never go past the end of the table.
+            let last_copy = (card.card_number + counts[card.card_number - 1]).min(cards.len());
+            processed_card_count += last_copy - card.card_number;
+            for card in &cards[card.card_number..last_copy] {
+                cards_to_process.push_back(card);
+            }
+        }
+        return Ok(processed_card_count.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies_stop_at_the_end_of_the_table() {
+        let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 3\n").unwrap();
+        assert_eq!(Day04::part2(&cards), Ok("3".to_string()));
+    }
+
+    #[test]
+    fn reports_points_too_large_to_count() {
+        let numbers = (1..=64).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+        let cards = Day04::parse(&format!("Card 1: {numbers} | {numbers}\n")).unwrap();
+        assert_eq!(Day04::part1(&cards), Err(SolveError::new("the points up to card 1 are too large to count")));
+        let numbers = (1..=63).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+        let cards = Day04::parse(&format!("Card 1: {numbers} | {numbers}\n")).unwrap();
+        assert_eq!(Day04::part1(&cards), Ok((1i64 << 62).to_string()));
+    }
+}
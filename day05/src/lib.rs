@@ -0,0 +1,196 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use std::collections::HashMap;
+use std::ops::Range;
+use common::{Line, Lines, ParseError, Solution, SolveError};
+use tracing::{debug, trace};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+/// The solver for day 5.
+pub struct Day05;
+
+#[derive(Debug)]
+/// The seeds and the maps that take them from category to category.
+pub struct Almanac {
+    /// The seeds line read as single seeds, for part 1.
+    pub seeds: Vec<i64>,
+    /// The seeds line read as pairs of range start and length, for part 2.
+    pub seed_ranges: Vec<Range<i64>>,
+    /// The maps out of each category, keyed by that source category.
+    pub mappings: HashMap<String, Vec<Mapping>>,
+    /// Every category in the order the maps chain them, from `seed` to `location`.
+    pub categories: Vec<String>,
+}
+
+/// One line of a map, sending `src..src + range` to `dst..dst + range`.
+#[derive(Debug)]
+pub struct Mapping {
+    /// The start of the source range.
+    pub src: i64,
+    /// The start of the destination range.
+    pub dst: i64,
+    /// The length of both ranges.
+    pub range: i64,
+    /// The category the map leads to.
+    pub dst_category: String,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        return parse_almanac(input);
+    }
+
+    fn part1(almanac: &Almanac) -> Result<String, SolveError> {
+        return Ok(almanac
+            .seeds
+            .iter()
+            .map(|seed| calculate_destination_value(seed, almanac))
+            .min()
+            .unwrap()
+            .to_string());
+    }
+
+    fn part2(almanac: &Almanac) -> Result<String, SolveError> {
+        let mut ranges: Vec<Range<i64>> = almanac.seed_ranges.iter().filter(|range| !range.is_empty()).cloned().collect();
+        for category in &almanac.categories[..almanac.categories.len() - 1] {
+            ranges = map_ranges(ranges, &almanac.mappings[category]);
+            debug!(category = %category, ranges = ranges.len(), "ranges mapped");
+        }
+        return match ranges.iter().map(|range| range.start).min() {
+            Some(location) => Ok(location.to_string()),
+            None => Err(SolveError::new("every seed range is empty")),
+        };
+    }
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    // There is always at least one block, as an empty input fails to split
+    let mut blocks = Lines::new(input).blocks()?;
+    let mut seeds_block = blocks.remove(0);
+    let (seeds, seed_ranges) = seeds_block.labeled("seeds:", |line| {
+        // Each seed keeps the line from where it starts, to point at ranges whose end overflows
+        let starts: Vec<(Line, i64)> = line.many(|line| Ok((*line, line.integer("a seed number")?)))?;
+        let seeds: Vec<i64> = starts.iter().map(|(_, seed)| *seed).collect();
+        if seeds.is_empty() {
+            return Err(line.unexpected("a seed number"));
+        }
+        if !seeds.len().is_multiple_of(2) {
+            return Err(line.unexpected("a seed range length"));
+        }
+        let seed_ranges = starts.chunks_exact(2)
+            .map(|pair| {
+                let ((start_line, start), (_, length)) = (&pair[0], &pair[1]);
+                return match start.checked_add(*length) {
+                    Some(end) => Ok(*start..end),
+                    None => Err(start_line.unexpected("a seed range ending below 2^63")),
+                };
+            })
+            .collect::<Result<_, _>>()?;
+        return Ok((seeds, seed_ranges));
+    })?;
+    seeds_block.end()?;
+    let mut mappings = HashMap::new();
+    let mut categories = vec![String::from("seed")];
+
+    for mut block in blocks {
+        let header_expected = "a map header like `seed-to-soil map:`";
+        let (src_category, dst_category) = block.line(header_expected, |line| {
+            let src_category = line.name(header_expected)?;
+            let previous_category = categories.last().unwrap();
+            if src_category != previous_category {
+                return Err(line.error(src_category, &format!("a map from `{previous_category}`")));
+            }
+            line.literal("-to-")?;
+            let dst_category = line.name(header_expected)?;
+            line.literal("map:")?;
+            return Ok((src_category, dst_category));
+        })?;
+        let category_mappings = block.each(|line| Ok(Mapping {
+            dst: line.integer("a destination range start")?,
+            src: line.integer("a source range start")?,
+            range: line.integer("a range length")?,
+            dst_category: dst_category.to_string(),
+        }))?;
+
+        mappings.insert(src_category.to_string(), category_mappings);
+        categories.push(dst_category.to_string());
+    }
+
+    return Ok(Almanac { seeds, seed_ranges, mappings, categories });
+}
+
+/// Follows `seed` through every map to the last category, tracing each number on the way.
+pub fn calculate_destination_value(seed: &i64, almanac: &Almanac) -> i64 {
+    let mut categories_iter = almanac.categories.iter();
+    let mut category = categories_iter.next().unwrap();
+    let mut current_number = *seed;
+    let destination = almanac.categories.last().unwrap();
+    trace!(category = %category, number = current_number);
+    while category != destination {
+        let mapping = almanac.mappings[category]
+            .iter()
+            .find(|m| Range { start: m.src, end: m.src + m.range }.contains(&current_number));
+        category = categories_iter.next().unwrap();
+        if let Some(m) = mapping {
+            current_number = m.dst + current_number - m.src;
+            assert_eq!(category, &m.dst_category);
+        }
+        trace!(category = %category, number = current_number);
+    }
+    return current_number;
+}
+
+/// Where every number in `ranges` goes through `mappings`, as ranges. The first mapping to cover a number moves it,
+/// and numbers none of them cover stay where they are.
+pub fn map_ranges(ranges: Vec<Range<i64>>, mappings: &[Mapping]) -> Vec<Range<i64>> {
+    let mut mapped = vec![];
+    let mut unmapped = ranges;
+    for m in mappings {
+        let offset = m.dst - m.src;
+        let mut rest = vec![];
+        for range in unmapped {
+            let start = range.start.max(m.src);
+            let end = range.end.min(m.src + m.range);
+            if start >= end {
+                rest.push(range);
+                continue;
+            }
+            mapped.push(start + offset..end + offset);
+            if range.start < start {
+                rest.push(range.start..start);
+            }
+            if end < range.end {
+                rest.push(end..range.end);
+            }
+        }
+        unmapped = rest;
+    }
+    mapped.extend(unmapped);
+    return mapped;
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_an_empty_seed_list() {
+        let error = Day05::parse("seeds:\n\nseed-to-location map:\n0 1 2\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (1, 7, Found::EndOfLine));
+    }
+
+    #[test]
+    fn rejects_seed_ranges_ending_past_i64() {
+        let error = Day05::parse("seeds: 1 2 9223372036854775800 8\n\nseed-to-location map:\n0 1 2\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+        assert_eq!(error.found, Found::Token("9223372036854775800".to_string()));
+        assert_eq!(error.expected, "a seed range ending below 2^63");
+        assert!(Day05::parse("seeds: 1 2 9223372036854775800 7\n\nseed-to-location map:\n0 1 2\n").is_ok());
+    }
+}
@@ -0,0 +1,77 @@
+//! Part 2 by brute force, following every seed of the ranges, to check the range splitting against.
+
+use crate::{calculate_destination_value, Almanac};
+
+/// The lowest location of any seed in the seed ranges, found by mapping every single seed forwards, or `None`
+/// if the ranges are all empty.
+pub fn lowest_location(almanac: &Almanac) -> Option<i64> {
+    return almanac.seed_ranges
+        .iter()
+        .flat_map(|range| range.clone())
+        .map(|seed| calculate_destination_value(&seed, almanac))
+        .min();
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use common::Solution;
+    use crate::Day05;
+    use super::*;
+
+    const SIZE: i64 = 24;
+
+    /// The lines of a map that cuts `0..SIZE` at `cuts` and shuffles the pieces, ordered by `keys`.
+    ///
+    /// Each map is a permutation of `0..SIZE`, as in the puzzle.
+    fn permutation_lines(cuts: &[i64], keys: &[u32]) -> Vec<String> {
+        let bounds: Vec<i64> = [0].iter().chain(cuts).chain(&[SIZE]).copied().collect();
+        let pieces: Vec<(i64, i64)> = bounds.windows(2).map(|pair| (pair[0], pair[1] - pair[0])).collect();
+        let mut order: Vec<usize> = (0..pieces.len()).collect();
+        order.sort_by_key(|i| keys[*i]);
+
+        let mut lines = vec![];
+        let mut dst = 0;
+        for i in order {
+            let (src, range) = pieces[i];
+            lines.push(format!("{dst} {src} {range}"));
+            dst += range;
+        }
+        return lines;
+    }
+
+    /// The lines of a map either shuffling `0..SIZE`, or sending arbitrary ranges anywhere, which leaves gaps
+    /// that keep their numbers and overlaps where the first line listed wins.
+    fn map_lines() -> impl Strategy<Value=Vec<String>> {
+        let permutation = (prop::collection::btree_set(1..SIZE, 0..4), prop::collection::vec(any::<u32>(), 5))
+            .prop_map(|(cuts, keys)| permutation_lines(&cuts.into_iter().collect::<Vec<_>>(), &keys));
+        let arbitrary = prop::collection::vec((0..SIZE, 0..SIZE, 1..SIZE / 2), 0..5)
+            .prop_map(|lines| lines.iter().map(|(dst, src, range)| format!("{dst} {src} {range}")).collect());
+        return prop_oneof![permutation, arbitrary];
+    }
+
+    fn almanac() -> impl Strategy<Value=String> {
+        let seeds = prop::collection::vec((0..SIZE + 4, 0..6i64), 1..4);
+        return (seeds, prop::collection::vec(map_lines(), 1..4)).prop_map(|(seeds, maps)| {
+            let seeds_line = seeds.iter().map(|(start, range)| format!("{start} {range}")).collect::<Vec<_>>().join(" ");
+            let mut lines = vec![format!("seeds: {seeds_line}")];
+            let mut categories = vec!["seed".to_string()];
+            categories.extend((1..maps.len()).map(|i| format!("category{i}")));
+            categories.push("location".to_string());
+            for (i, map) in maps.into_iter().enumerate() {
+                lines.push(String::new());
+                lines.push(format!("{}-to-{} map:", categories[i], categories[i + 1]));
+                lines.extend(map);
+            }
+            lines.join("\n")
+        });
+    }
+
+    proptest! {
+        #[test]
+        fn range_splitting_finds_the_lowest_location(input in almanac()) {
+            let almanac = Day05::parse(&input).unwrap();
+            prop_assert_eq!(Day05::part2(&almanac).ok(), lowest_location(&almanac).map(|location| location.to_string()));
+        }
+    }
+}
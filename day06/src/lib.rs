@@ -0,0 +1,100 @@
+//! Day 6: Wait For It
+
+use std::iter::zip;
+use common::{Lines, ParseError, Solution, SolveError};
+
+/// The solver for day 6.
+pub struct Day06;
+
+/// The race times and record distances, in the order they are listed.
+pub struct Races {
+    /// How long each race lasts.
+    pub times: Vec<u64>,
+    /// The best distance so far in each race.
+    pub records: Vec<u64>,
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        let mut lines = Lines::new(input);
+        let times: Vec<u64> = lines.labeled("Time:", |line| {
+            let times = line.many(|line| line.integer("a race time"))?;
+            if times.is_empty() {
+                return Err(line.unexpected("a race time"));
+            }
+            return Ok(times);
+        })?;
+        // One record per race, so a missing or extra one is an error rather than a race left out
+        let records = lines.labeled("Distance:", |line| {
+            let mut records = Vec::with_capacity(times.len());
+            for _ in &times {
+                records.push(line.integer("a record distance for each race")?);
+            }
+            return Ok(records);
+        })?;
+        lines.end()?;
+        return Ok(Races { times, records });
+    }
+
+    fn part1(races: &Races) -> Result<String, SolveError> {
+        let mut result = 1;
+        for (time, record) in zip(&races.times, &races.records) {
+            result *= ways_to_win(*time, *record);
+        }
+        return Ok(result.to_string());
+    }
+
+    fn part2(races: &Races) -> Result<String, SolveError> {
+        let (Some(time), Some(record)) = (join_digits(&races.times), join_digits(&races.records)) else {
+            return Err(SolveError::new("the time or record read as a single number does not fit in 64 bits"));
+        };
+        return Ok(ways_to_win(time, record).to_string());
+    }
+}
+
+/// The number formed by writing `numbers` next to each other, ignoring the spaces between them, if it fits.
+fn join_digits(numbers: &[u64]) -> Option<u64> {
+    return numbers.iter().map(|n| n.to_string()).collect::<String>().parse().ok();
+}
+
+/// How many ways of holding the button beat the `record` distance in a race lasting `time`.
+pub fn ways_to_win(time: u64, record: u64) -> u64 {
+    let mut count = 0;
+    for t in 0..time {
+        let d = distance_covered(t, time);
+        if d > record {
+            count += 1;
+        }
+    }
+    return count;
+}
+
+/// How far the boat goes when the button is held for `hold_time` of the race.
+pub fn distance_covered(hold_time: u64, race_time: u64) -> u64 {
+    return (race_time - hold_time) * hold_time;
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn needs_a_record_for_every_race() {
+        let error = Day06::parse("Time:\nDistance:\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (1, 6, Found::EndOfLine));
+        let error = Day06::parse("Time: 7 15 30\nDistance: 9\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (2, 12, Found::EndOfLine));
+        let error = Day06::parse("Time: 7\nDistance: 9 40\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (2, 13, Found::Token("40".to_string())));
+    }
+
+    #[test]
+    fn reports_races_too_long_to_join() {
+        let races = Day06::parse("Time: 1234567890 1234567890 1\nDistance: 1 2 3\n").unwrap();
+        assert!(Day06::part2(&races).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Day 7: Camel Cards
+
+use std::collections::HashMap;
+use common::{Lines, ParseError, Solution, SolveError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The solver for day 7.
+pub struct Day07;
+
+/// A hand of five cards from `23456789TJQKA`, and what was bid on it.
+#[derive(Clone)]
+pub struct Bet {
+    /// The five cards, as drawn.
+    pub hand: String,
+    /// The bid on the hand.
+    pub bid: i64,
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<Bet>;
+
+    fn parse(input: &str) -> Result<Vec<Bet>, ParseError> {
+        return Lines::new(input).each(|line| {
+            let hand_expected = "a hand of five cards from `23456789TJQKA`";
+            let hand = line.word_of(|c| "23456789TJQKA".contains(c), hand_expected)?;
+            if hand.chars().count() != 5 {
+                return Err(line.error(hand, hand_expected));
+            }
+            return Ok(Bet { hand: hand.to_string(), bid: line.integer("a bid")? });
+        });
+    }
+
+    fn part1(bets: &Vec<Bet>) -> Result<String, SolveError> {
+        return Ok(total_winnings(bets, false).to_string());
+    }
+
+    fn part2(bets: &Vec<Bet>) -> Result<String, SolveError> {
+        return Ok(total_winnings(bets, true).to_string());
+    }
+}
+
+/// Every bid multiplied by the rank of its hand. With `joker`, `J` is the weakest card but counts as any other.
+pub fn total_winnings(bets: &[Bet], joker: bool) -> i64 {
+    #[cfg(feature = "parallel")]
+    let scored = bets.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let scored = bets.iter();
+    let mut scored: Vec<(i64, i64)> = scored.map(|bet| (score(&bet.hand, joker), bet.bid)).collect();
+    scored.sort_by_key(|(score, _)| *score);
+    return scored.iter().enumerate().map(|(i, (_, bid))| bid * (i as i64 + 1)).sum::<i64>();
+}
+
+fn get_sorted_counts(hand: &str, joker: bool) -> Vec<i32> {
+    let mut count_map: HashMap<char, i32> = HashMap::new();
+    for card in hand.chars() {
+        count_map.insert(card, *count_map.get(&card).get_or_insert(&0) + 1);
+    }
+    let joker_count = **count_map.get(&'J').get_or_insert(&0);
+    if joker {
+        count_map.remove(&'J');
+    }
+    let mut counts: Vec<i32> = count_map.values().copied().collect();
+    counts.sort_by(|i1, i2| i2.cmp(i1));
+    if joker {
+        if counts.is_empty() {
+            counts.push(joker_count);
+        } else {
+            counts[0] += joker_count;
+        }
+    }
+    return counts;
+}
+
+/// A number that orders hands by type, then card by card.
+pub fn score(hand: &str, joker: bool) -> i64 {
+    let counts = get_sorted_counts(hand, joker);
+    let pow = match &counts[..] {
+        [5] => 6,
+        [4, 1] => 5,
+        [3, 2] => 4,
+        [3, 1, 1] => 3,
+        [2, 2, 1] => 2,
+        [2, ..] => 1,
+        _ => 0
+    };
+    let mut score = 10_i64.pow(pow);
+    let card_scores: HashMap<char, usize> = if joker {
+        "0J123456789TQKA"
+    } else {
+        "0123456789TJQKA"
+    }
+        .chars().enumerate().map(|(i, c)| (c, i)).collect();
+    for card in hand.chars() {
+        score = score*100 + card_scores[&card] as i64;
+    }
+    return score;
+}
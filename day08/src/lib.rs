@@ -0,0 +1,257 @@
+//! Day 8: Haunted Wasteland
+
+use std::collections::HashMap;
+use std::iter::successors;
+use gcd::Gcd;
+use common::{Cycle, Lines, ParseError, Solution, SolveError};
+use tracing::debug;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+/// The solver for day 8.
+pub struct Day08;
+
+/// One of the left/right instructions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Turn {
+    /// Take the left neighbour.
+    LEFT,
+    /// Take the right neighbour.
+    RIGHT,
+}
+
+impl Turn {
+    /// The node this turn leads to, out of a node's left and right neighbours.
+    pub fn follow<'a>(&self, (left, right): &'a (String, String)) -> &'a String {
+        return match self {
+            Turn::LEFT => left,
+            Turn::RIGHT => right,
+        };
+    }
+}
+
+/// The instructions and the nodes they lead through.
+pub struct Network {
+    /// The instructions, which repeat once they run out.
+    pub directions: Vec<Turn>,
+    /// Each node's left and right neighbours.
+    pub nodes: HashMap<String, (String, String)>,
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        let [mut directions_block, mut nodes_block] = Lines::new(input)
+            .split_blocks(["a line of `L`/`R` directions", "a blank line before the nodes"])?;
+        let directions = directions_block.line("a line of `L`/`R` directions", |line| line.chars(|c| match c {
+            'L' => Some(Turn::LEFT),
+            'R' => Some(Turn::RIGHT),
+            _ => None,
+        }, "`L` or `R`"))?;
+        directions_block.end()?;
+        let mut nodes: HashMap<String, (String, String)> = HashMap::new();
+        let mut neighbours = vec![];
+        nodes_block.each(|line| {
+            let node = line.name("a node name")?;
+            if nodes.contains_key(node) {
+                return Err(line.error(node, "a node name not used before"));
+            }
+            line.literal("=")?;
+            let (left, right) = line.between("(", ")", |line| {
+                let left = line.name("a left node")?;
+                line.literal(",")?;
+                return Ok((left, line.name("a right node")?));
+            })?;
+            neighbours.extend([(*line, left), (*line, right)]);
+            nodes.insert(node.to_string(), (left.to_string(), right.to_string()));
+            return Ok(());
+        })?;
+        for (line, neighbour) in neighbours {
+            if !nodes.contains_key(neighbour) {
+                return Err(line.error(neighbour, "the name of a node"));
+            }
+        }
+        return Ok(Network { directions, nodes });
+    }
+
+    fn part1(network: &Network) -> Result<String, SolveError> {
+        let Network { directions, nodes } = network;
+        if !nodes.contains_key("AAA") {
+            return Err(SolveError::new("there is no node AAA to start from"));
+        }
+        let mut current_node = "AAA".to_string();
+        let dest = "ZZZ";
+        let mut counter = 0;
+        let mut directions_iter = directions.iter().cycle();
+
+        while current_node != dest {
+            // Every state of node and direction has been seen by then, so the walk goes round in circles
+            if counter > nodes.len() * directions.len() {
+                return Err(SolveError::new("ZZZ cannot be reached from AAA"));
+            }
+            current_node = directions_iter.next().unwrap().follow(&nodes[&current_node]).to_string();
+            counter += 1;
+        }
+        return Ok(counter.to_string());
+    }
+
+    fn part2(network: &Network) -> Result<String, SolveError> {
+        let Network { directions, nodes } = network;
+        let mut start_nodes: Vec<_> = nodes.keys().filter(|s| s.ends_with("A")).map(|s| s.to_string()).collect();
+        start_nodes.sort();
+        if start_nodes.is_empty() {
+            return Err(SolveError::new("no node ends in A, so there are no ghosts"));
+        }
+        let mut ghosts: Vec<(Cycle, Vec<usize>)> = vec![];
+
+        for node in &start_nodes {
+            // A ghost's state is where it stands and which direction it reads next
+            let step = |(index, node): &(usize, String)| ((index + 1) % directions.len(), directions[*index].follow(&nodes[node]).to_string());
+            let initial_state = (0, node.to_string());
+            let cycle = Cycle::find(&initial_state, step);
+            // Every later step on a Z node repeats one of these
+            let z_steps: Vec<usize> = successors(Some(initial_state), |state| Some(step(state)))
+                .take(cycle.start + cycle.period)
+                .enumerate()
+                .filter_map(|(i, (_, node))| node.ends_with("Z").then_some(i))
+                .collect();
+            if z_steps.is_empty() {
+                return Err(SolveError::new(&format!("the ghost starting at {node} never reaches a node ending in Z")));
+            }
+            debug!(start = %node, cycle.start, cycle.period, ?z_steps, "ghost cycle");
+            ghosts.push((cycle, z_steps));
+        }
+
+        // A ghost only on a Z node at the end of each lap, whose lap is at least as long as the way into it,
+        // is on one exactly at the multiples of its period
+        if ghosts.iter().all(|(cycle, z_steps)| z_steps == &[cycle.period] && cycle.start <= cycle.period) {
+            let steps = ghosts.iter().try_fold(1, |steps, (cycle, _)| lcm(steps, cycle.period as u64));
+            return steps
+                .map(|steps| steps.to_string())
+                .ok_or_else(|| SolveError::new("the ghosts only meet after more steps than can be counted"));
+        }
+        return match first_meeting(&ghosts) {
+            Some(steps) => Ok(steps.to_string()),
+            None => Err(SolveError::new("the ghosts never all stand on nodes ending in Z at once")),
+        };
+    }
+}
+
+/// The first step where every ghost, given as its cycle and the steps up to the end of its first lap where it is
+/// on a Z node, is on one.
+///
+/// Before every ghost has entered its cycle the steps are checked one by one. After that, a ghost is on a Z node
+/// at the steps congruent to one of its Z steps in the cycle, modulo its period, so the steps where they all are
+/// make up a few congruence classes, found with the Chinese remainder theorem.
+pub fn first_meeting(ghosts: &[(Cycle, Vec<usize>)]) -> Option<u128> {
+    let all_in_cycles = ghosts.iter().map(|(cycle, _)| cycle.start).max().unwrap_or(0);
+    for n in 1..all_in_cycles {
+        if ghosts.iter().all(|(cycle, z_steps)| z_steps.contains(&cycle.reduce(n))) {
+            return Some(n as u128);
+        }
+    }
+
+    let mut classes: Vec<(i128, i128)> = vec![(0, 1)];
+    for (cycle, z_steps) in ghosts {
+        let residues = z_steps.iter()
+            .filter(|z_step| **z_step >= cycle.start)
+            .map(|z_step| (*z_step as i128, cycle.period as i128));
+        let mut combined: Vec<(i128, i128)> = residues
+            .flat_map(|residue| classes.iter().filter_map(move |class| combine(*class, residue)))
+            .collect();
+        combined.sort();
+        combined.dedup();
+        classes = combined;
+    }
+    let lowest = all_in_cycles.max(1) as i128;
+    return classes.iter()
+        .map(|(residue, modulus)| residue + ((lowest - residue).max(0) + modulus - 1) / modulus * modulus)
+        .min()
+        .map(|steps| steps as u128);
+}
+
+/// The congruence class of the numbers in both `residue` classes, each a remainder and its modulus, if any.
+fn combine((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, x, _) = extended_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+    let modulus = m1 / g * m2;
+    let k = ((r2 - r1) / g * x).rem_euclid(m2 / g);
+    return Some(((r1 + m1 * k).rem_euclid(modulus), modulus));
+}
+
+/// The gcd of `a` and `b`, with `x` and `y` such that `a * x + b * y` is the gcd.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    return (g, y, x - (a / b) * y);
+}
+
+fn lcm(first: u64, second: u64) -> Option<u64> {
+    return (first / first.gcd(second)).checked_mul(second);
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn ghosts_that_never_meet_have_no_answer() {
+        let network = Day08::parse("L\n\n1A = (1B, 1B)\n1B = (1B, 1B)\n").unwrap();
+        let error = Day08::part2(&network).unwrap_err();
+        assert_eq!(error.to_string(), "the ghost starting at 1A never reaches a node ending in Z");
+        assert!(Day08::part1(&network).is_err());
+
+        // The first ghost is on 1Z only once, at step 1, which is also its period, so the steps where they meet
+        // are not the multiples of the periods
+        let network = Day08::parse(concat!(
+            "L\n\n",
+            "1A = (1Z, 1Z)\n1Z = (1B, 1B)\n1B = (1B, 1B)\n",
+            "2A = (2Y, 2Y)\n2Y = (2Z, 2Z)\n2Z = (2Y, 2Y)\n",
+        )).unwrap();
+        assert!(Day08::part2(&network).is_err());
+    }
+
+    #[test]
+    fn rejects_nodes_not_defined_once() {
+        let error = Day08::parse("L\n\nAAA = (BBB, BBB)\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (3, 8, Found::Token("BBB".to_string())));
+        let error = Day08::parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\nAAA = (ZZZ, ZZZ)\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (5, 1, "a node name not used before"));
+    }
+
+    #[test]
+    fn lcm_reports_overflow() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn ghosts_can_meet_before_their_cycles() {
+        let network = Day08::parse(concat!(
+            "L\n\n",
+            "1A = (1Z, 1Z)\n1Z = (1B, 1B)\n1B = (1B, 1B)\n",
+            "2A = (2Z, 2Z)\n2Z = (2Y, 2Y)\n2Y = (2Z, 2Z)\n",
+        )).unwrap();
+        assert_eq!(Day08::part2(&network), Ok("1".to_string()));
+    }
+
+    #[test]
+    fn ghosts_can_reach_z_twice_per_cycle() {
+        // The first ghost is on a Z node at steps 1 and 3 of every 5, the second at every fourth step. They meet at
+        // the first ghost's second Z node on step 8, well before they are both on their first ones, on step 16.
+        let network = Day08::parse(concat!(
+            "L\n\n",
+            "1A = (1Z, 1Z)\n1Z = (1B, 1B)\n1B = (1YZ, 1YZ)\n1YZ = (1C, 1C)\n1C = (1A, 1A)\n",
+            "2A = (2B, 2B)\n2B = (2C, 2C)\n2C = (2D, 2D)\n2D = (2Z, 2Z)\n2Z = (2B, 2B)\n",
+        )).unwrap();
+        assert_eq!(Day08::part2(&network), Ok("8".to_string()));
+    }
+}
@@ -0,0 +1,64 @@
+//! Part 2 by walking the ghosts step by step, to check the cycle arithmetic against.
+
+use std::collections::HashSet;
+use crate::Network;
+
+/// Walks every ghost in lockstep until they all stand on a node ending in `Z`, or `None` if they never do.
+pub fn ghost_steps(network: &Network) -> Option<u64> {
+    let Network { directions, nodes } = network;
+    let mut ghosts: Vec<&String> = nodes.keys().filter(|node| node.ends_with("A")).collect();
+    ghosts.sort();
+    let mut seen = HashSet::new();
+    let mut steps = 0;
+    loop {
+        if ghosts.iter().all(|node| node.ends_with("Z")) {
+            return Some(steps);
+        }
+        let index = steps as usize % directions.len();
+        if !seen.insert((index, ghosts.clone())) {
+            return None;
+        }
+        for node in ghosts.iter_mut() {
+            *node = directions[index].follow(&nodes[*node]);
+        }
+        steps += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use common::Solution;
+    use crate::Day08;
+    use super::*;
+
+    /// A network of up to seven nodes, named by their index and an `A`, `Z` or `B` ending. The first
+    /// node is always a ghost's start, and most of the others end in `Z` so the ghosts often meet.
+    fn network() -> impl Strategy<Value=String> {
+        let node = (prop::sample::select(vec!['A', 'B', 'B', 'Z', 'Z', 'Z']), 0..7usize, 0..7usize);
+        let directions = prop::collection::vec(prop::sample::select(vec!['L', 'R']), 1..5);
+        return (directions, prop::collection::vec(node, 1..8)).prop_map(|(directions, nodes)| {
+            let names: Vec<String> = nodes.iter()
+                .enumerate()
+                .map(|(i, (ending, _, _))| format!("N{i}{}", if i == 0 { 'A' } else { *ending }))
+                .collect();
+            let mut lines = vec![directions.into_iter().collect(), String::new()];
+            for (name, (_, left, right)) in names.iter().zip(&nodes) {
+                lines.push(format!("{name} = ({}, {})", names[left % names.len()], names[right % names.len()]));
+            }
+            lines.join("\n")
+        });
+    }
+
+    proptest! {
+        #[test]
+        fn cycle_analysis_matches_walking(input in network()) {
+            let network = Day08::parse(&input).unwrap();
+            match ghost_steps(&network) {
+                Some(steps) => prop_assert_eq!(Day08::part2(&network), Ok(steps.to_string())),
+                // Most random networks have no step where every ghost is on a Z node
+                None => prop_assert!(Day08::part2(&network).is_err()),
+            }
+        }
+    }
+}
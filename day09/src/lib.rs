@@ -0,0 +1,45 @@
+//! Day 9: Mirage Maintenance
+
+use std::iter::zip;
+use common::{Lines, ParseError, Solution, SolveError};
+
+/// The solver for day 9.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        return Lines::new(input).each(|line| line.many(|line| line.integer("a number")));
+    }
+
+    fn part1(sequences: &Vec<Vec<i64>>) -> Result<String, SolveError> {
+        return Ok(sequences.iter().map(|s| next_value(s)).sum::<i64>().to_string());
+    }
+
+    fn part2(sequences: &Vec<Vec<i64>>) -> Result<String, SolveError> {
+        let reverted_sequences: Vec<Vec<i64>> = sequences.iter().map(|s| s.iter().cloned().rev().collect()).collect();
+        return Ok(reverted_sequences.iter().map(|s| next_value(s)).sum::<i64>().to_string());
+    }
+}
+
+/// Extrapolates the value after the end of `seq` from its repeated differences.
+pub fn next_value(seq: &[i64]) -> i64 {
+    let mut sequences = vec![seq.to_vec()];
+    while !sequences.last().unwrap().iter().all(|i| *i == 0) {
+        let last_sequence = sequences.last().unwrap();
+        let mut next_sequence = Vec::with_capacity(last_sequence.len() - 1);
+        for (v1, v2) in zip(&last_sequence[0..last_sequence.len()-1], &last_sequence[1..]) {
+            next_sequence.push(v2 - v1);
+        }
+        sequences.push(next_sequence);
+    }
+    sequences.last_mut().unwrap().push(0);
+    for i in (0..sequences.len()-1).rev() {
+        let next_sequence = sequences[i+1].clone();
+        let sequence = &mut sequences[i];
+        sequence.push(sequence.last().unwrap() + next_sequence.last().unwrap());
+    }
+    return *sequences.first().unwrap().last().unwrap();
+}
@@ -0,0 +1,246 @@
+//! Day 10: Pipe Maze
+
+use std::cmp::max;
+use std::collections::{HashMap, HashSet, VecDeque};
+use common::{Cell, Colour, Coord, Direction, Grid, ParseError, Picture, Solution, SolveError};
+use tracing::debug;
+
+/// The solver for day 10.
+pub struct Day10;
+
+/// One tile of the map.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    /// A `.`.
+    GROUND,
+    /// One of `|-LJ7F`.
+    PIPE(char),
+    /// The `S` the animal starts on.
+    START,
+}
+
+impl Cell for Tile {
+    const EXPECTED: &'static str = "one of `.|-LJ7FS`";
+
+    fn from_char(c: char) -> Option<Tile> {
+        return match c {
+            '.' => Some(Tile::GROUND),
+            '|' | '-' | 'L' | 'J' | '7' | 'F' => Some(Tile::PIPE(c)),
+            'S' => Some(Tile::START),
+            _ => None,
+        };
+    }
+
+    fn to_char(&self) -> char {
+        return match self {
+            Tile::GROUND => '.',
+            Tile::PIPE(c) => *c,
+            Tile::START => 'S',
+        };
+    }
+}
+
+/// The pipes on the map and where the animal starts.
+pub struct Maze {
+    /// Where the `S` is.
+    pub start_pos: Coord,
+    /// The tiles each tile's pipe connects to. The start connects to the two pipes at the ends of its loop.
+    pub transitions: HashMap<Coord, Vec<Coord>>,
+    /// The map as drawn.
+    pub tiles: Grid<Tile>,
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        let mut tiles: Grid<Tile> = Grid::parse(input)?;
+        let mut transitions: HashMap<Coord, Vec<Coord>> = HashMap::new();
+        let mut start_pos: Option<Coord> = None;
+
+        for (position, &tile) in tiles.iter() {
+            let coord = Coord::from(position);
+            let edges = connections(tile)
+                .into_iter()
+                .filter_map(|direction| coord.step_within(direction, tiles.width(), tiles.height()))
+                .collect();
+            transitions.insert(coord, edges);
+            if tile == Tile::START {
+                start_pos = Some(coord);
+            }
+        }
+
+        let start_pos = start_pos.ok_or_else(|| ParseError::end_of_input(tiles.height(), "a starting tile `S`"))?;
+        let ends = loop_ends(start_pos, &transitions).ok_or_else(|| {
+            ParseError::new(start_pos.y as usize + 1, start_pos.x as usize + 1, "S", "a start on a loop of pipes")
+        })?;
+        transitions.insert(start_pos, ends.to_vec());
+
+        // The start is a pipe like any other when counting crossings of the loop
+        let start_directions: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|direction| transitions[&start_pos].contains(&(start_pos + *direction)))
+            .collect();
+        if let Some(pipe) = "|-LJ7F".chars().find(|pipe| {
+            let pipe_directions = connections(Tile::PIPE(*pipe));
+            pipe_directions.len() == start_directions.len() && pipe_directions.iter().all(|d| start_directions.contains(d))
+        }) {
+            tiles[start_pos] = Tile::PIPE(pipe);
+        }
+
+        return Ok(Maze { start_pos, transitions, tiles });
+    }
+
+    fn part1(maze: &Maze) -> Result<String, SolveError> {
+        let (steps, _) = solve(maze.start_pos, &maze.transitions, &maze.tiles);
+        return Ok(steps.to_string());
+    }
+
+    fn part2(maze: &Maze) -> Result<String, SolveError> {
+        let (_, contained) = solve(maze.start_pos, &maze.transitions, &maze.tiles);
+        return Ok(contained.to_string());
+    }
+
+    fn render(maze: &Maze) -> Option<Picture> {
+        return Some(picture(maze));
+    }
+}
+
+const BACKGROUND: Colour = Colour::rgb(24, 24, 32);
+const JUNK: Colour = Colour::rgb(56, 56, 68);
+const ENCLOSED: Colour = Colour::rgb(64, 160, 96);
+const START: Colour = Colour::rgb(220, 60, 60);
+const LOOP: Colour = Colour::rgb(250, 190, 60);
+
+/// The two pipes next to `start` at either end of a loop through it, if there is one. Other pipes may lead into
+/// the start without being part of the loop.
+fn loop_ends(start: Coord, transitions: &HashMap<Coord, Vec<Coord>>) -> Option<[Coord; 2]> {
+    let leads_in = |c: &Coord| transitions.get(c).is_some_and(|edges| edges.contains(&start));
+    for first in Direction::ALL.map(|direction| start + direction).into_iter().filter(leads_in) {
+        let (mut previous, mut current) = (start, first);
+        // A pipe connects to two tiles at most, so following one either comes back to the start or stops
+        for _ in 0..transitions.len() {
+            let edges = &transitions[&current];
+            if !edges.contains(&previous) {
+                break;
+            }
+            let Some(next) = edges.iter().find(|edge| **edge != previous) else {
+                break;
+            };
+            if *next == start {
+                return Some([first, current]);
+            }
+            (previous, current) = (current, *next);
+        }
+    }
+    return None;
+}
+
+/// The distance to the point of the loop farthest from `start`, and how many tiles the loop encloses.
+pub fn solve(start: Coord, transitions: &HashMap<Coord, Vec<Coord>>, tiles: &Grid<Tile>) -> (u64, u64) {
+    let (max_distance, visited) = walk_loop(start, transitions);
+    return (max_distance, enclosed_tiles(&visited, tiles).len() as u64);
+}
+
+/// The distance to the point of the loop farthest from `start`, and every tile of the loop.
+pub fn walk_loop(start: Coord, transitions: &HashMap<Coord, Vec<Coord>>) -> (u64, HashSet<Coord>) {
+    let mut visited: HashSet<Coord> = HashSet::from([start]);
+    let mut node_queue: VecDeque<(u64, Coord)> = VecDeque::from_iter(transitions[&start].iter().map(|c| (1, *c)));
+    let mut max_distance = 0;
+    while !node_queue.is_empty() {
+        let (distance, next_coord) = node_queue.pop_front().unwrap();
+        max_distance = max(max_distance, distance);
+        visited.insert(next_coord);
+        for edge in &transitions[&next_coord] {
+            if !visited.contains(edge) {
+                node_queue.push_back((distance + 1, *edge));
+            }
+        }
+    }
+    debug!(length = visited.len(), max_distance, "walked the loop");
+    return (max_distance, visited);
+}
+
+/// The tiles inside the loop, found by counting how often each row crosses it.
+pub fn enclosed_tiles(loop_tiles: &HashSet<Coord>, tiles: &Grid<Tile>) -> Vec<Coord> {
+    let mut tiles_enclosed = vec![];
+    for y in 0..tiles.height() {
+        let mut within_loop = false;
+        for x in 0..tiles.width() {
+            if loop_tiles.contains(&Coord::from((x, y))) {
+                if is_obstacle(tiles[(x, y)]) {
+                    within_loop = !within_loop;
+                }
+            } else if within_loop {
+                tiles_enclosed.push(Coord::from((x, y)));
+            }
+        }
+    }
+    return tiles_enclosed;
+}
+
+/// The loop drawn through the middle of its tiles, over the tiles it encloses.
+pub fn picture(maze: &Maze) -> Picture {
+    let (_, loop_tiles) = walk_loop(maze.start_pos, &maze.transitions);
+    let mut picture = Picture::from_grid(&maze.tiles, BACKGROUND, |tile| if *tile == Tile::GROUND { BACKGROUND } else { JUNK });
+    for coord in enclosed_tiles(&loop_tiles, &maze.tiles) {
+        picture.fill(coord, ENCLOSED);
+    }
+    picture.fill(maze.start_pos, START);
+    for coord in &loop_tiles {
+        for next in &maze.transitions[coord] {
+            picture.line(vec![Picture::centre(*coord), Picture::centre(*next)], LOOP);
+        }
+    }
+    return picture;
+}
+
+fn connections(tile: Tile) -> Vec<Direction> {
+    return match tile {
+        Tile::PIPE('|') => vec![Direction::UP, Direction::DOWN],
+        Tile::PIPE('-') => vec![Direction::LEFT, Direction::RIGHT],
+        Tile::PIPE('L') => vec![Direction::UP, Direction::RIGHT],
+        Tile::PIPE('J') => vec![Direction::UP, Direction::LEFT],
+        Tile::PIPE('7') => vec![Direction::LEFT, Direction::DOWN],
+        Tile::PIPE('F') => vec![Direction::DOWN, Direction::RIGHT],
+        _ => vec![],
+    };
+}
+
+fn is_obstacle(tile: Tile) -> bool {
+    return matches!(tile, Tile::PIPE('|' | 'L' | 'J'));
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_tiles() {
+        let error = Day10::parse(".....\n.S-7.\n.|.|.\n.L+J.\n.....\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (4, 3, Found::Token("+".to_string())));
+        assert_eq!(error.expected, "one of `.|-LJ7FS`");
+    }
+
+    #[test]
+    fn follows_the_loop_when_more_pipes_lead_into_the_start() {
+        // The `|` above the start leads into it too, but goes nowhere
+        let maze = Day10::parse(".|...\n.S-7.\n.|.|.\n.L-J.\n.....\n").unwrap();
+        assert_eq!(maze.transitions[&maze.start_pos], [Coord::new(1, 2), Coord::new(2, 1)]);
+        assert_eq!(maze.tiles[(1, 1)], Tile::PIPE('F'));
+        assert_eq!((Day10::part1(&maze), Day10::part2(&maze)), (Ok("4".to_string()), Ok("1".to_string())));
+
+        let error = Day10::parse(".....\n.S-7.\n...|.\n.L-J.\n.....\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 2, "a start on a loop of pipes"));
+    }
+
+    #[test]
+    fn counts_the_start_as_the_pipe_it_stands_in_for() {
+        // Crossing the start, standing in for a `|`, leaves the loop, so the tile right of it is outside
+        let maze = Day10::parse(".....\n.F-7.\n.|.S.\n.L-J.\n.....\n").unwrap();
+        assert_eq!(maze.tiles[(3, 2)], Tile::PIPE('|'));
+        assert_eq!(Day10::part2(&maze), Ok("1".to_string()));
+    }
+}
@@ -0,0 +1,122 @@
+//! Day 11: Cosmic Expansion
+
+use std::cmp::{max, min};
+use common::{Cell, Coord, Grid, ParseError, Solution, SolveError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The solver for day 11.
+pub struct Day11;
+
+/// One pixel of the image.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Pixel {
+    /// A `.`.
+    EMPTY,
+    /// A `#`.
+    GALAXY,
+}
+
+impl Cell for Pixel {
+    const EXPECTED: &'static str = "`.` or `#`";
+
+    fn from_char(c: char) -> Option<Pixel> {
+        return match c {
+            '.' => Some(Pixel::EMPTY),
+            '#' => Some(Pixel::GALAXY),
+            _ => None,
+        };
+    }
+
+    fn to_char(&self) -> char {
+        return match self {
+            Pixel::EMPTY => '.',
+            Pixel::GALAXY => '#',
+        };
+    }
+}
+
+/// The galaxies as seen in the image, with the rows and columns that hold none and so expand.
+pub struct Universe {
+    /// Where each galaxy is in the image.
+    pub galaxies: Vec<Coord>,
+    /// The rows without galaxies.
+    pub empty_lines: Vec<i64>,
+    /// The columns without galaxies.
+    pub empty_columns: Vec<i64>,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Universe;
+
+    fn parse(input: &str) -> Result<Universe, ParseError> {
+        let grid: Grid<Pixel> = Grid::parse(input)?;
+        let (empty_lines, empty_columns) = expand(&grid);
+        let galaxies: Vec<Coord> = grid.iter()
+            .filter(|(_, pixel)| **pixel == Pixel::GALAXY)
+            .map(|(position, _)| Coord::from(position))
+            .collect();
+
+        return Ok(Universe { galaxies, empty_lines, empty_columns });
+    }
+
+    fn part1(universe: &Universe) -> Result<String, SolveError> {
+        return Ok(total_distance(universe, 2).to_string());
+    }
+
+    fn part2(universe: &Universe) -> Result<String, SolveError> {
+        return Ok(total_distance(universe, 1000000).to_string());
+    }
+}
+
+/// The sum of the distances between every pair of galaxies, once each empty row and column
+/// has become `expansion` of them.
+pub fn total_distance(universe: &Universe, expansion: u64) -> u64 {
+    let Universe { galaxies, empty_lines, empty_columns } = universe;
+    #[cfg(feature = "parallel")]
+    let firsts = galaxies.par_iter().enumerate();
+    #[cfg(not(feature = "parallel"))]
+    let firsts = galaxies.iter().enumerate();
+    return firsts.map(|(i, galaxy)| {
+        let mut result = 0;
+        for galaxy2 in galaxies[i+1..galaxies.len()].iter() {
+            let length = galaxy.manhattan(*galaxy2);
+            let empty_spaces = empty_lines.iter()
+                .filter(|y| **y > min(galaxy.y, galaxy2.y) && **y < max(galaxy.y, galaxy2.y))
+                .count() as u64
+                +
+                empty_columns.iter()
+                    .filter(|x| **x > min(galaxy.x, galaxy2.x) && **x < max(galaxy.x, galaxy2.x))
+                    .count() as u64;
+            result += length + (empty_spaces * (expansion - 1));
+        }
+        result
+    }).sum();
+}
+
+fn expand(grid: &Grid<Pixel>) -> (Vec<i64>, Vec<i64>) {
+    let empty_lines = grid.rows()
+        .enumerate()
+        .filter_map(|(y, line)| line.iter().all(|pixel| *pixel == Pixel::EMPTY).then_some(y as i64))
+        .collect();
+    let empty_columns = grid.columns()
+        .enumerate()
+        .filter_map(|(x, mut column)| column.all(|pixel| *pixel == Pixel::EMPTY).then_some(x as i64))
+        .collect();
+
+    return (empty_lines, empty_columns);
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_pixels() {
+        let error = Day11::parse("#..\n.*.\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (2, 2, Found::Token("*".to_string())));
+        assert_eq!(error.expected, "`.` or `#`");
+    }
+}
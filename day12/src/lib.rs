@@ -0,0 +1,126 @@
+//! Day 12: Hot Springs
+
+use std::collections::{HashMap, VecDeque};
+use common::{Line, Lines, ParseError, Solution, SolveError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+/// The solver for day 12.
+pub struct Day12;
+
+/// A row of `.`, `#` and `?` springs, with the sizes of its groups of damaged (`#`) springs in order.
+pub type Row = (String, VecDeque<usize>);
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+        return Lines::new(input).each(parse_line);
+    }
+
+    fn part1(rows: &Vec<Row>) -> Result<String, SolveError> {
+        return Ok(total_arrangements(rows).to_string());
+    }
+
+    fn part2(rows: &Vec<Row>) -> Result<String, SolveError> {
+        let unfolded_rows: Vec<_> = rows
+            .iter()
+            .map(|(line, counts)| unfold(line, counts))
+            .collect();
+        return Ok(total_arrangements(&unfolded_rows).to_string());
+    }
+}
+
+/// The sum of every row's arrangements.
+#[cfg(not(feature = "parallel"))]
+pub fn total_arrangements(rows: &[Row]) -> i64 {
+    let mut cache = HashMap::new();
+    return rows.iter().map(|(line, counts)| count_arrangements(line.clone(), counts.clone(), &mut cache)).sum::<i64>();
+}
+
+/// The sum of every row's arrangements, with a cache for each thread.
+#[cfg(feature = "parallel")]
+pub fn total_arrangements(rows: &[Row]) -> i64 {
+    return rows.par_iter()
+        .map_init(HashMap::new, |cache, (line, counts)| count_arrangements(line.clone(), counts.clone(), cache))
+        .sum::<i64>();
+}
+
+/// The row repeated five times, joined by unknown springs.
+pub fn unfold(line: &String, counts: &VecDeque<usize>) -> Row {
+    let mut unfolded_counts = VecDeque::new();
+    for _ in 0..5 {
+        for i in counts {
+            unfolded_counts.push_back(*i);
+        }
+    }
+    return ([line].repeat(5).iter().map(|s| s.to_string()).collect::<Vec<String>>().join("?"), unfolded_counts);
+}
+
+/// How many ways the unknown springs in `line` can be filled in to give the groups in `counts`.
+///
+/// Results are memoised in `cache`, which can be shared between rows.
+pub fn count_arrangements(line: String, counts: VecDeque<usize>, cache: &mut HashMap<Row, i64>) -> i64 {
+    let key = (line, counts);
+    if let Some(arrangements) = cache.get(&key) {
+        return *arrangements;
+    }
+    let (line, counts) = key.clone();
+    let arrangements = count_uncached_arrangements(line, counts, cache);
+    cache.insert(key, arrangements);
+    return arrangements;
+}
+
+fn count_uncached_arrangements(line: String, counts: VecDeque<usize>, cache: &mut HashMap<Row, i64>) -> i64 {
+    if counts.is_empty() {
+        if !line.chars().any(|c| c == '#') {
+            return 1;
+        }
+        return 0;
+    }
+    if line.is_empty() {
+        return 0;
+    }
+    if line.starts_with("#") {
+        let mut new_counts = counts.clone();
+        let mut chars_to_cut = new_counts.pop_front().unwrap() + 1;
+        if line.len() < chars_to_cut - 1 || line[0..chars_to_cut - 1].chars().any(|c| c == '.') {
+            return 0;
+        }
+        if line.len() >= chars_to_cut {
+            if line[chars_to_cut-1..chars_to_cut].starts_with('#') {
+                return 0;
+            }
+        } else {
+            chars_to_cut -= 1;
+        }
+        return count_arrangements(line[chars_to_cut..line.len()].to_string(), new_counts, cache);
+    }
+    let tail = line[1..line.len()].to_string();
+    if line.starts_with(".") {
+        return count_arrangements(tail, counts, cache);
+    }
+    return count_arrangements(format!("#{tail}"), counts.clone(), cache) + count_arrangements(tail, counts, cache);
+}
+
+fn parse_line(line: &mut Line) -> Result<Row, ParseError> {
+    let springs = line.word_of(|c| ".#?".contains(c), "a row of `.`, `#` and `?` springs")?;
+    let counts = line.list(",", |line| line.integer("a group size"))?;
+    return Ok((springs.to_string(), counts.into()));
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_negative_group_sizes() {
+        let error = Day12::parse("???.### -1,1,3\n").unwrap_err();
+        assert_eq!((error.column, error.found), (9, Found::Token("-1".to_string())));
+    }
+}
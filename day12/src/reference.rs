@@ -0,0 +1,66 @@
+//! Arrangements counted by trying every filling of the unknowns, to check the cached count against.
+
+/// The sizes of the groups of damaged springs in a row without unknowns.
+pub fn groups(springs: &str) -> Vec<usize> {
+    return springs.split('.').filter(|group| !group.is_empty()).map(|group| group.len()).collect();
+}
+
+/// Counts arrangements by trying every way of filling in the unknown springs.
+pub fn count_arrangements(line: &str, counts: &[usize]) -> i64 {
+    let unknowns: Vec<usize> = line.char_indices().filter(|(_, c)| *c == '?').map(|(i, _)| i).collect();
+    let mut arrangements = 0;
+    for mask in 0..1u64 << unknowns.len() {
+        let mut springs: Vec<char> = line.chars().collect();
+        for (bit, i) in unknowns.iter().enumerate() {
+            springs[*i] = if mask >> bit & 1 == 1 { '#' } else { '.' };
+        }
+        if groups(&springs.iter().collect::<String>()) == counts {
+            arrangements += 1;
+        }
+    }
+    return arrangements;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+    use proptest::prelude::*;
+    use super::*;
+
+    fn springs(length: usize) -> impl Strategy<Value=String> {
+        return prop::collection::vec(prop::sample::select(vec!['.', '#', '?']), 1..=length)
+            .prop_map(|springs| springs.into_iter().collect());
+    }
+
+    /// A row with its groups taken from one of its own arrangements, so there is at least one.
+    fn solvable_row() -> impl Strategy<Value=(String, Vec<usize>)> {
+        return prop::collection::vec(any::<(bool, bool)>(), 1..=14).prop_map(|springs| {
+            let filled: String = springs.iter().map(|(damaged, _)| if *damaged { '#' } else { '.' }).collect();
+            let line = springs.iter().zip(filled.chars()).map(|((_, hidden), c)| if *hidden { '?' } else { c }).collect();
+            (line, groups(&filled))
+        });
+    }
+
+    fn optimised(line: &str, counts: &[usize]) -> i64 {
+        return crate::count_arrangements(line.to_string(), VecDeque::from(counts.to_vec()), &mut HashMap::new());
+    }
+
+    proptest! {
+        #[test]
+        fn counts_solvable_rows((line, counts) in solvable_row()) {
+            prop_assert_eq!(optimised(&line, &counts), count_arrangements(&line, &counts));
+        }
+
+        #[test]
+        fn counts_arbitrary_rows(line in springs(12), counts in prop::collection::vec(1..5usize, 0..4)) {
+            prop_assert_eq!(optimised(&line, &counts), count_arrangements(&line, &counts));
+        }
+
+        #[test]
+        fn counts_unfolded_rows(line in springs(2), counts in prop::collection::vec(1..3usize, 1..3)) {
+            let (unfolded_line, unfolded_counts) = crate::unfold(&line, &VecDeque::from(counts));
+            let unfolded_counts: Vec<usize> = unfolded_counts.into();
+            prop_assert_eq!(optimised(&unfolded_line, &unfolded_counts), count_arrangements(&unfolded_line, &unfolded_counts));
+        }
+    }
+}
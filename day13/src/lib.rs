@@ -0,0 +1,119 @@
+//! Day 13: Point of Incidence
+
+use common::{Cell, Grid, Lines, ParseError, Solution, SolveError};
+
+/// The solver for day 13.
+pub struct Day13;
+
+/// One spot of a pattern.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Terrain {
+    /// A `.`.
+    ASH,
+    /// A `#`.
+    ROCK,
+}
+
+impl Cell for Terrain {
+    const EXPECTED: &'static str = "`.` or `#`";
+
+    fn from_char(c: char) -> Option<Terrain> {
+        return match c {
+            '.' => Some(Terrain::ASH),
+            '#' => Some(Terrain::ROCK),
+            _ => None,
+        };
+    }
+
+    fn to_char(&self) -> char {
+        return match self {
+            Terrain::ASH => '.',
+            Terrain::ROCK => '#',
+        };
+    }
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Grid<Terrain>>;
+
+    fn parse(input: &str) -> Result<Vec<Grid<Terrain>>, ParseError> {
+        return Lines::new(input).blocks()?.iter_mut().map(Lines::grid).collect();
+    }
+
+    fn part1(grids: &Vec<Grid<Terrain>>) -> Result<String, SolveError> {
+        return summarize(grids, false);
+    }
+
+    fn part2(grids: &Vec<Grid<Terrain>>) -> Result<String, SolveError> {
+        return summarize(grids, true);
+    }
+}
+
+/// The sum of every pattern's [`solve`], or an error naming the first pattern without a line of reflection.
+fn summarize(grids: &[Grid<Terrain>], smudge: bool) -> Result<String, SolveError> {
+    let mut total = 0;
+    for (i, grid) in grids.iter().enumerate() {
+        total += solve(grid, smudge).ok_or_else(|| {
+            let kind = if smudge { "smudged " } else { "" };
+            SolveError::new(&format!("pattern {} has no {kind}line of reflection", i + 1))
+        })?;
+    }
+    return Ok(total.to_string());
+}
+
+/// The number of columns left of the pattern's line of reflection, or 100 times the number of rows
+/// above it. With `smudge`, the reflection must be off by exactly one character. `None` if there is no
+/// such line.
+pub fn solve(grid: &Grid<Terrain>, smudge: bool) -> Option<usize> {
+    for i in 1..grid.width() {
+        if check_reflection(grid, i, smudge) {
+            return Some(i);
+        }
+    }
+    let transposed = grid.transpose();
+    for i in 1..transposed.width() {
+        if check_reflection(&transposed, i, smudge) {
+            return Some(100 * i);
+        }
+    }
+    return None;
+}
+
+
+fn check_reflection(grid: &Grid<Terrain>, start: usize, smudge: bool) -> bool {
+    let mut smudge_found = false;
+    for row in grid.rows() {
+        let left = row[0..start].iter().rev();
+        let right = &row[start..row.len()];
+        let differences = right.iter().zip(left).filter(|(a, b)| a != b).count();
+        if smudge && !smudge_found && differences == 1 {
+            smudge_found = true;
+            continue;
+        }
+        if differences != 0 {
+            return false;
+        }
+    }
+    return !smudge || smudge_found;
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_terrain() {
+        let error = Day13::parse("##\n..\n\n#.\n.O\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (5, 2, Found::Token("O".to_string())));
+        assert_eq!(error.expected, "`.` or `#`");
+    }
+
+    #[test]
+    fn reports_patterns_without_a_line_of_reflection() {
+        let patterns = Day13::parse("##\n..\n\n#.\n..\n").unwrap();
+        assert_eq!(Day13::part1(&patterns), Err(SolveError::new("pattern 2 has no line of reflection")));
+        assert_eq!(Day13::part2(&patterns), Err(SolveError::new("pattern 1 has no smudged line of reflection")));
+    }
+}
@@ -0,0 +1,178 @@
+//! Day 14: Parabolic Reflector Dish
+
+use common::{Cell, Colour, Coord, Cycle, Grid, ParseError, Picture, Simulation, Solution, SolveError};
+
+/// The solver for day 14.
+pub struct Day14;
+
+/// One spot of the platform.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Rock {
+    /// A `.`, with no rock.
+    EMPTY,
+    /// A `#`, which stays put.
+    CUBE,
+    /// An `O`, which rolls when the platform tilts.
+    ROUNDED,
+}
+
+impl Cell for Rock {
+    const EXPECTED: &'static str = "one of `.#O`";
+
+    fn from_char(c: char) -> Option<Rock> {
+        return match c {
+            '.' => Some(Rock::EMPTY),
+            '#' => Some(Rock::CUBE),
+            'O' => Some(Rock::ROUNDED),
+            _ => None,
+        };
+    }
+
+    fn to_char(&self) -> char {
+        return match self {
+            Rock::EMPTY => '.',
+            Rock::CUBE => '#',
+            Rock::ROUNDED => 'O',
+        };
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Grid<Rock>;
+
+    fn parse(input: &str) -> Result<Grid<Rock>, ParseError> {
+        return Grid::parse(input);
+    }
+
+    fn part1(platform: &Grid<Rock>) -> Result<String, SolveError> {
+        let mut platform = platform.clone();
+        tilt_north(&mut platform);
+        return Ok(total_load(&platform).to_string());
+    }
+
+    fn part2(platform: &Grid<Rock>) -> Result<String, SolveError> {
+        let cycle = Cycle::find(platform, spin_cycle);
+        let platform = cycle.state_at(platform, spin_cycle, 1000000000);
+        return Ok(total_load(&platform).to_string());
+    }
+
+    fn render(platform: &Grid<Rock>) -> Option<Picture> {
+        return Some(picture(platform));
+    }
+
+    fn simulate(platform: &Grid<Rock>) -> Option<Box<dyn Simulation>> {
+        return Some(Box::new(Tilting { platform: platform.clone(), tilts: 0 }));
+    }
+}
+
+const SIDES: [&str; 4] = ["north", "west", "south", "east"];
+
+/// The spin cycles of part 2, one tilt at a time.
+pub struct Tilting {
+    /// The platform after the tilts so far.
+    pub platform: Grid<Rock>,
+    /// How many tilts have been made.
+    pub tilts: usize,
+}
+
+impl Simulation for Tilting {
+    fn step(&mut self) -> bool {
+        // Turned so the side to tilt towards is on top, then turned back
+        let turns = self.tilts % 4;
+        let mut platform = self.platform.clone();
+        for _ in 0..turns {
+            platform = platform.rotate_clockwise();
+        }
+        tilt_north(&mut platform);
+        for _ in 0..(4 - turns) % 4 {
+            platform = platform.rotate_clockwise();
+        }
+        self.platform = platform;
+        self.tilts += 1;
+        return true;
+    }
+
+    fn frame(&self) -> String {
+        return self.platform.to_string();
+    }
+
+    fn status(&self) -> String {
+        if self.tilts == 0 {
+            return format!("Before the first cycle, load {}", total_load(&self.platform));
+        }
+        return format!("Cycle {}, tilted {}, load {}",
+                       (self.tilts - 1) / 4 + 1, SIDES[(self.tilts - 1) % 4], total_load(&self.platform));
+    }
+}
+
+const EMPTY: Colour = Colour::rgb(235, 235, 225);
+const CUBE: Colour = Colour::rgb(70, 70, 80);
+const ROUNDED: Colour = Colour::rgb(200, 120, 40);
+const ROLLED_AWAY: Colour = Colour::rgb(240, 210, 170);
+
+/// The platform tilted north, with the places the round rocks rolled away from shaded.
+pub fn picture(platform: &Grid<Rock>) -> Picture {
+    let mut tilted = platform.clone();
+    tilt_north(&mut tilted);
+    let mut picture = Picture::from_grid(&tilted, EMPTY, |rock| match rock {
+        Rock::EMPTY => EMPTY,
+        Rock::CUBE => CUBE,
+        Rock::ROUNDED => ROUNDED,
+    });
+    for (position, rock) in platform.iter() {
+        if *rock == Rock::ROUNDED && tilted[position] != Rock::ROUNDED {
+            picture.fill(Coord::from(position), ROLLED_AWAY);
+        }
+    }
+    return picture;
+}
+
+/// Rolls every round rock (`O`) north until it meets the edge, a cube rock (`#`) or another round rock.
+pub fn tilt_north(platform: &mut Grid<Rock>) {
+    for x in 0..platform.width() {
+        let mut top = 0;
+        for y in 0..platform.height() {
+            match platform[(x, y)] {
+                Rock::CUBE => top = y + 1,
+                Rock::ROUNDED => {
+                    platform[(x, y)] = Rock::EMPTY;
+                    platform[(x, top)] = Rock::ROUNDED;
+                    top += 1;
+                }
+                Rock::EMPTY => {}
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east in turn. Each clockwise turn brings the next side to the top.
+pub fn spin_cycle(platform: &Grid<Rock>) -> Grid<Rock> {
+    let mut platform = platform.clone();
+    for _ in 0..4 {
+        tilt_north(&mut platform);
+        platform = platform.rotate_clockwise();
+    }
+    return platform;
+}
+
+/// The load on the north support beams: each round rock weighs its distance to the south edge.
+pub fn total_load(platform: &Grid<Rock>) -> usize {
+    return platform.iter()
+        .filter(|(_, rock)| **rock == Rock::ROUNDED)
+        .map(|((_, y), _)| platform.height() - y)
+        .sum();
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_rocks() {
+        let error = Day14::parse("O.#\n.o.\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (2, 2, Found::Token("o".to_string())));
+        assert_eq!(error.expected, "one of `.#O`");
+    }
+}
@@ -0,0 +1,136 @@
+//! Day 15: Lens Library
+//!
+//! The input is the initialization sequence, read into its comma-separated [`Step`]s.
+
+use common::{Lines, ParseError, Solution, SolveError};
+
+/// The solver for day 15.
+pub struct Day15;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A lens and the label of the box it goes in.
+pub struct Lens {
+    /// The letters before the operation.
+    pub label: String,
+    /// The digit after `=`.
+    pub focal_length: u8
+}
+
+/// What a step does with its lens: `=` puts it in its box, `-` takes it out.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Put the lens in its box, replacing any with the same label.
+    ADD,
+    /// Take the lens with this label out of its box.
+    REMOVE
+}
+
+/// One step of the initialization sequence. Removed lenses have a focal length of 0.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Step {
+    /// The step as written, which the first star hashes.
+    pub text: String,
+    /// Whether the lens goes in or out.
+    pub operation: Operation,
+    /// The lens the step handles.
+    pub lens: Lens,
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+        let mut lines = Lines::new(input);
+        let steps = lines.line("the initialization sequence", |line| {
+            // The first star hashes every character of a step, so spaces cannot be skipped like elsewhere
+            if let Some(space) = line.text.find(char::is_whitespace) {
+                let width = line.text[space..].chars().next().unwrap().len_utf8();
+                return Err(line.error(&line.text[space..space + width], "a step without spaces"));
+            }
+            let steps = line.list(",", |line| {
+                let label = line.name("a step like `label=3` or `label-`")?.to_string();
+                if line.optional("-") {
+                    return Ok((Operation::REMOVE, Lens { label, focal_length: 0 }));
+                }
+                line.literal("=")?;
+                let focal_length = line.integer("a focal length")?;
+                return Ok((Operation::ADD, Lens { label, focal_length }));
+            })?;
+            // Without spaces, each step read is exactly the text between two commas
+            return Ok(line.text.split(',')
+                .zip(steps)
+                .map(|(text, (operation, lens))| Step { text: text.to_string(), operation, lens })
+                .collect());
+        })?;
+        lines.end()?;
+        return Ok(steps);
+    }
+
+    fn part1(steps: &Vec<Step>) -> Result<String, SolveError> {
+        return Ok(steps.iter().map(|step| hash(&step.text)).sum::<i64>().to_string());
+    }
+
+    fn part2(steps: &Vec<Step>) -> Result<String, SolveError> {
+        let mut hash_table: Vec<Vec<Lens>> = Vec::with_capacity(256);
+        for _ in 0..256 {
+            hash_table.push(vec![]);
+        }
+
+        for Step { operation, lens, .. } in steps {
+            let lens_box = &mut hash_table[hash(lens.label.as_str()) as usize];
+            match operation {
+                Operation::ADD => {
+                    match lens_box.iter().position(|l| l.label == lens.label) {
+                        None => { lens_box.push(lens.clone()) }
+                        Some(i) => { lens_box[i] = lens.clone() }
+                    }
+                },
+                Operation::REMOVE => {
+                    match lens_box.iter().position(|l| l.label == lens.label) {
+                        None => {}
+                        Some(i) => { lens_box.remove(i); }
+                    }
+                }
+            }
+        }
+
+        let mut focusing_power: usize = 0;
+
+        for (i, lens_box) in hash_table.iter().enumerate() {
+            for (j, lens) in lens_box.iter().enumerate() {
+                focusing_power += (i + 1) * (j + 1) * lens.focal_length as usize;
+            }
+        }
+
+        return Ok(focusing_power.to_string());
+    }
+}
+
+/// The HASH algorithm: a number from 0 to 255 for `s`, which also picks a lens' box.
+pub fn hash(s: &str) -> i64 {
+    return s.chars().fold(0, |acc, c| (acc + (c as i64)) * 17 % 256);
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn reads_each_step_once() {
+        let steps = Day15::parse("rn=1,cm-\n").unwrap();
+        assert_eq!(steps[0], Step {
+            text: "rn=1".to_string(),
+            operation: Operation::ADD,
+            lens: Lens { label: "rn".to_string(), focal_length: 1 },
+        });
+        assert_eq!((steps[1].operation, hash(&steps[1].text)), (Operation::REMOVE, 253));
+
+        // Spaces would change the first star's hashes, so they are rejected rather than skipped
+        let error = Day15::parse("rn = 1,cm-\n").unwrap_err();
+        assert_eq!((error.column, error.found), (3, Found::Token(" ".to_string())));
+        let error = Day15::parse("rn=1,cm=x\n").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (9, "a focal length"));
+    }
+}
@@ -0,0 +1,233 @@
+//! Day 16: The Floor Will Be Lava
+
+use std::collections::{HashSet, VecDeque};
+use common::{Cell, Colour, Coord, Direction, Grid, ParseError, Picture, Simulation, Solution, SolveError};
+use tracing::trace;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The solver for day 16.
+pub struct Day16;
+
+/// One tile of the contraption. Mirrors and splitters keep the character they are drawn with.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Tile {
+    /// A `.`.
+    EMPTY,
+    /// A `/` or `\`.
+    MIRROR(char),
+    /// A `|` or `-`.
+    SPLITTER(char),
+}
+
+impl Cell for Tile {
+    const EXPECTED: &'static str = "one of `.|-/\\`";
+
+    fn from_char(c: char) -> Option<Tile> {
+        return match c {
+            '.' => Some(Tile::EMPTY),
+            '/' | '\\' => Some(Tile::MIRROR(c)),
+            '|' | '-' => Some(Tile::SPLITTER(c)),
+            _ => None,
+        };
+    }
+
+    fn to_char(&self) -> char {
+        return match self {
+            Tile::EMPTY => '.',
+            Tile::MIRROR(c) | Tile::SPLITTER(c) => *c,
+        };
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+        return Grid::parse(input);
+    }
+
+    fn part1(lines: &Grid<Tile>) -> Result<String, SolveError> {
+        return Ok(propagate(lines, Coord::ORIGIN, Direction::RIGHT).to_string());
+    }
+
+    fn part2(lines: &Grid<Tile>) -> Result<String, SolveError> {
+        let height = lines.height() as i64;
+        let width = lines.width() as i64;
+        let mut entries = vec![];
+        for y in 0..height {
+            entries.push((Coord::new(0, y), Direction::RIGHT));
+            entries.push((Coord::new(width - 1, y), Direction::LEFT));
+        }
+        for x in 0..width {
+            entries.push((Coord::new(x, 0), Direction::DOWN));
+            entries.push((Coord::new(x, height - 1), Direction::UP));
+        }
+
+        #[cfg(feature = "parallel")]
+        let entries = entries.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let entries = entries.into_iter();
+        let max_propagation = entries
+            .map(|(start, direction)| {
+                let energized = propagate(lines, start, direction);
+                trace!(x = start.x, y = start.y, ?direction, energized, "entry");
+                energized
+            })
+            .max()
+            .unwrap_or(0);
+        return Ok(max_propagation.to_string());
+    }
+
+    fn render(grid: &Grid<Tile>) -> Option<Picture> {
+        return Some(picture(grid));
+    }
+
+    fn simulate(grid: &Grid<Tile>) -> Option<Box<dyn Simulation>> {
+        return Some(Box::new(Beam::new(grid)));
+    }
+}
+
+/// How many tiles end up energized by a beam entering `start` heading in `start_direction`.
+pub fn propagate(grid: &Grid<Tile>, start: Coord, start_direction: Direction) -> usize {
+    return energize(grid, start, start_direction).len();
+}
+
+/// Every tile a beam entering `start` heading in `start_direction` passes through.
+pub fn energize(grid: &Grid<Tile>, start: Coord, start_direction: Direction) -> HashSet<Coord> {
+    let mut coords = HashSet::new();
+    let mut coord_queue = VecDeque::from([(start, start_direction)]);
+    let mut visited = HashSet::new();
+    while !coord_queue.is_empty() {
+        let (coord, direction) = coord_queue.pop_front().unwrap();
+        if !visited.insert((coord, direction)) {
+            continue;
+        }
+        coords.insert(coord);
+        coord_queue.extend(advance(grid, coord, direction));
+    }
+    return coords;
+}
+
+/// Where a beam on `coord` heading in `direction` goes next, still within the grid.
+fn advance(grid: &Grid<Tile>, coord: Coord, direction: Direction) -> Vec<(Coord, Direction)> {
+    let directions = match grid[coord] {
+        Tile::EMPTY => vec![direction],
+        Tile::MIRROR(mirror) => vec![reflection_direction(direction, mirror)],
+        Tile::SPLITTER(splitter) => splitter_directions(direction, splitter),
+    };
+    return directions.into_iter()
+        .filter_map(|next_direction| Some((coord.step_within(next_direction, grid.width(), grid.height())?, next_direction)))
+        .collect();
+}
+
+/// The beam from the top left spreading through the contraption, one tile further each step.
+pub struct Beam {
+    /// The contraption.
+    pub grid: Grid<Tile>,
+    /// Where the beam's fronts are, and which way they are heading.
+    pub fronts: Vec<(Coord, Direction)>,
+    /// Each tile and heading the beam has been at, so loops are not followed twice.
+    pub visited: HashSet<(Coord, Direction)>,
+    /// The tiles the beam has passed.
+    pub energized: HashSet<Coord>,
+    /// How many steps have been taken.
+    pub steps: usize,
+}
+
+impl Beam {
+    /// A beam about to enter the top left tile heading right.
+    pub fn new(grid: &Grid<Tile>) -> Beam {
+        return Beam {
+            grid: grid.clone(),
+            fronts: vec![(Coord::ORIGIN, Direction::RIGHT)],
+            visited: HashSet::from([(Coord::ORIGIN, Direction::RIGHT)]),
+            energized: HashSet::from([Coord::ORIGIN]),
+            steps: 0,
+        };
+    }
+}
+
+impl Simulation for Beam {
+    fn step(&mut self) -> bool {
+        if self.fronts.is_empty() {
+            return false;
+        }
+        let mut fronts = vec![];
+        for (coord, direction) in &self.fronts {
+            for next in advance(&self.grid, *coord, *direction) {
+                if self.visited.insert(next) {
+                    self.energized.insert(next.0);
+                    fronts.push(next);
+                }
+            }
+        }
+        self.fronts = fronts;
+        self.steps += 1;
+        return true;
+    }
+
+    fn frame(&self) -> String {
+        let mut frame = self.grid.map(|tile| tile.to_char());
+        for coord in &self.energized {
+            if frame[*coord] == '.' {
+                frame[*coord] = '#';
+            }
+        }
+        for (coord, direction) in &self.fronts {
+            frame[*coord] = match direction {
+                Direction::UP => '^',
+                Direction::DOWN => 'v',
+                Direction::LEFT => '<',
+                Direction::RIGHT => '>',
+            };
+        }
+        return frame.to_string();
+    }
+
+    fn status(&self) -> String {
+        return format!("{} beam fronts, {} tiles energized", self.fronts.len(), self.energized.len());
+    }
+}
+
+fn reflection_direction(direction: Direction, mirror: char) -> Direction {
+    return match mirror {
+        '\\' => direction.reflect_backslash(),
+        '/' => direction.reflect_slash(),
+        _ => panic!()
+    };
+}
+
+fn splitter_directions(direction: Direction, splitter: char) -> Vec<Direction> {
+    return match splitter {
+        '|' if direction.is_horizontal() => vec![Direction::UP, Direction::DOWN],
+        '-' if direction.is_vertical() => vec![Direction::LEFT, Direction::RIGHT],
+        '|' | '-' => vec![direction],
+        _ => panic!()
+    };
+}
+
+const DARK: Colour = Colour::rgb(20, 20, 28);
+const ENERGIZED: Colour = Colour::rgb(120, 80, 20);
+const DEVICE: Colour = Colour::rgb(160, 200, 255);
+
+/// The contraption with the tiles energized by the beam from the top left lit up.
+pub fn picture(grid: &Grid<Tile>) -> Picture {
+    let mut picture = Picture::new(grid.width(), grid.height(), DARK);
+    for coord in energize(grid, Coord::ORIGIN, Direction::RIGHT) {
+        picture.fill(coord, ENERGIZED);
+    }
+    for (position, tile) in grid.iter() {
+        let (x, y) = (position.0 as f64, position.1 as f64);
+        let ends = match tile {
+            Tile::EMPTY => continue,
+            Tile::MIRROR('/') => [(x, y + 1.0), (x + 1.0, y)],
+            Tile::MIRROR(_) => [(x, y), (x + 1.0, y + 1.0)],
+            Tile::SPLITTER('|') => [(x + 0.5, y), (x + 0.5, y + 1.0)],
+            Tile::SPLITTER(_) => [(x, y + 0.5), (x + 1.0, y + 0.5)],
+        };
+        picture.line(ends.to_vec(), DEVICE);
+    }
+    return picture;
+}
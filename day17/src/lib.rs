@@ -0,0 +1,140 @@
+//! Day 17: Clumsy Crucible
+
+use common::{a_star, Cell, Colour, Coord, Direction, Grid, ParseError, Path, Picture, Solution, SolveError};
+
+/// The solver for day 17.
+pub struct Day17;
+
+/// How much heat is lost entering a city block, read from its digit. Every block loses some, which the search's
+/// heuristic relies on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HeatLoss(pub u64);
+
+impl Cell for HeatLoss {
+    const EXPECTED: &'static str = "a heat loss digit from 1 to 9";
+
+    fn from_char(c: char) -> Option<HeatLoss> {
+        return ('1'..='9').contains(&c).then(|| HeatLoss(c.to_digit(10).unwrap() as u64));
+    }
+
+    fn to_char(&self) -> char {
+        return char::from_digit(self.0 as u32, 10).unwrap();
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct State {
+    position: Coord,
+    steps_taken: u8,
+    direction: Direction,
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Grid<HeatLoss>;
+
+    fn parse(input: &str) -> Result<Grid<HeatLoss>, ParseError> {
+        return Grid::parse(input);
+    }
+
+    fn part1(weights: &Grid<HeatLoss>) -> Result<String, SolveError> {
+        return minimal_heat_loss(weights, 1, 3).map(|loss| loss.to_string()).ok_or_else(unreachable_factory);
+    }
+
+    fn part2(weights: &Grid<HeatLoss>) -> Result<String, SolveError> {
+        return minimal_heat_loss(weights, 4, 10).map(|loss| loss.to_string()).ok_or_else(unreachable_factory);
+    }
+
+    fn render(weights: &Grid<HeatLoss>) -> Option<Picture> {
+        return Some(picture(weights));
+    }
+}
+
+fn unreachable_factory() -> SolveError {
+    return SolveError::new("the crucible cannot reach the factory");
+}
+
+/// The least heat lost on the way to the bottom right, with a crucible that must move at least
+/// `min_steps` and at most `max_steps` in a straight line before it turns or stops. `None` if it cannot
+/// get there, as on a map too small to ever move `min_steps` blocks.
+pub fn minimal_heat_loss(weights: &Grid<HeatLoss>, min_steps: u8, max_steps: u8) -> Option<u64> {
+    return best_route(weights, min_steps, max_steps).map(|path| path.cost);
+}
+
+/// Every block the crucible passes through on its least lossy way to the bottom right, if it can get there.
+pub fn route(weights: &Grid<HeatLoss>, min_steps: u8, max_steps: u8) -> Option<Vec<Coord>> {
+    let mut route = vec![Coord::ORIGIN];
+    for state in best_route(weights, min_steps, max_steps)?.states {
+        if *route.last().unwrap() != state.position {
+            route.push(state.position);
+        }
+    }
+    return Some(route);
+}
+
+fn best_route(weights: &Grid<HeatLoss>, min_steps: u8, max_steps: u8) -> Option<Path<State>> {
+    let (width, height) = (weights.width(), weights.height());
+    let goal = Coord::new(width as i64 - 1, height as i64 - 1);
+
+    let neighbours = |state: &State| {
+        let mut moves = vec![(state.direction, state.steps_taken + 1)];
+        if state.steps_taken >= min_steps {
+            moves.push((state.direction.turn_left(), 1));
+            moves.push((state.direction.turn_right(), 1));
+        }
+        return moves.into_iter()
+            .filter(|(_, steps_taken)| *steps_taken <= max_steps)
+            .filter_map(|(direction, steps_taken)| {
+                let position = state.position.step_within(direction, width, height)?;
+                let weight = weights[position].0;
+                Some((State { position, steps_taken, direction }, weight))
+            })
+            .collect::<Vec<_>>();
+    };
+
+    let starts = [Direction::RIGHT, Direction::DOWN].map(|direction| State { position: Coord::ORIGIN, steps_taken: 0, direction });
+    // Every block loses at least 1, so the distance left never overestimates the heat lost on the way
+    return a_star(starts,
+                  neighbours,
+                  |state| state.position == goal && state.steps_taken >= min_steps,
+                  |state| state.position.manhattan(goal));
+}
+
+const COOL: Colour = Colour::rgb(250, 240, 200);
+const HOT: Colour = Colour::rgb(150, 30, 20);
+const CRUCIBLE: Colour = Colour::rgb(40, 120, 220);
+const ULTRA_CRUCIBLE: Colour = Colour::rgb(30, 170, 90);
+
+/// The map shaded by heat loss, with the routes of the crucibles that can reach the factory.
+pub fn picture(weights: &Grid<HeatLoss>) -> Picture {
+    let mut picture = Picture::from_grid(weights, COOL, |weight| COOL.mix(HOT, (weight.0 as f64 - 1.0) / 8.0));
+    for (route, colour) in [(route(weights, 1, 3), CRUCIBLE), (route(weights, 4, 10), ULTRA_CRUCIBLE)] {
+        if let Some(route) = route {
+            picture.line(route.into_iter().map(Picture::centre).collect(), colour);
+        }
+    }
+    return picture;
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_blocks_without_heat_loss() {
+        // With free blocks the distance left could overestimate, and A* found 10 here where the best is 9
+        let error = Day17::parse("01\n90\n00\n90\n90\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (1, 1, Found::Token("0".to_string())));
+    }
+
+    #[test]
+    fn maps_too_small_for_a_route_have_no_answer() {
+        let weights = Day17::parse("5\n").unwrap();
+        assert_eq!(Day17::part1(&weights), Err(unreachable_factory()));
+        let weights = Day17::parse("123\n456\n789\n").unwrap();
+        assert_eq!(Day17::part1(&weights), Ok("20".to_string()));
+        assert_eq!(Day17::part2(&weights), Err(unreachable_factory()));
+        assert!(route(&weights, 4, 10).is_none());
+    }
+}
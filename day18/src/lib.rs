@@ -0,0 +1,107 @@
+//! Day 18: Lavaduct Lagoon
+
+use common::{Colour, Coord, Direction, Line, Lines, ParseError, Picture, Solution, SolveError};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+/// The solver for day 18.
+pub struct Day18;
+
+/// One line of the dig plan.
+pub struct Instruction {
+    /// The trench dug by the plan as written, as a direction times a distance.
+    pub path: Coord,
+    /// The six hex digits of the colour, which hide the real instruction for part 2.
+    pub hex: String,
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        return Lines::new(input).each(parse);
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<String, SolveError> {
+        return Ok(lagoon_size(instructions.iter().map(|instruction| instruction.path)).to_string());
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<String, SolveError> {
+        return Ok(lagoon_size(instructions.iter().map(|instruction| hex_to_path(&instruction.hex))).to_string());
+    }
+
+    fn render(instructions: &Vec<Instruction>) -> Option<Picture> {
+        return Some(picture(instructions));
+    }
+}
+
+const GROUND: Colour = Colour::rgb(90, 70, 50);
+const LAGOON: Colour = Colour::rgb(250, 80, 20);
+
+/// The lagoon dug by the plan as written, with each cube of trench painted its colour.
+pub fn picture(instructions: &[Instruction]) -> Picture {
+    let mut position = Coord::ORIGIN;
+    let mut trench = vec![];
+    let mut vertices = vec![];
+    for instruction in instructions {
+        vertices.push(position);
+        let direction = Coord::new(instruction.path.x.signum(), instruction.path.y.signum());
+        let colour = Colour::from_hex(&format!("#{}", instruction.hex)).unwrap_or(Colour::BLACK);
+        for _ in 0..instruction.path.x.abs() + instruction.path.y.abs() {
+            position += direction;
+            trench.push((position, colour));
+        }
+    }
+
+    // Shifted so the top left of the trench is at the origin
+    let min = Coord::new(vertices.iter().map(|c| c.x).min().unwrap_or(0), vertices.iter().map(|c| c.y).min().unwrap_or(0));
+    let max = Coord::new(vertices.iter().map(|c| c.x).max().unwrap_or(0), vertices.iter().map(|c| c.y).max().unwrap_or(0));
+    let mut picture = Picture::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, GROUND);
+    for (position, colour) in trench {
+        picture.fill(position - min, colour);
+    }
+    picture.polygon(vertices.into_iter().map(|vertex| Picture::centre(vertex - min)).collect(), LAGOON);
+    return picture;
+}
+
+/// How many cubic metres of lava the lagoon holds once the trench along `paths` and its inside are dug out.
+pub fn lagoon_size(paths: impl Iterator<Item=Coord>) -> u64 {
+    let mut vertex = Coord::ORIGIN;
+    let mut vertices = vec![vertex];
+    let mut perimeter = 0;
+    for path in paths {
+        let next_vertex = vertex + path;
+        perimeter += next_vertex.manhattan(vertex);
+        vertex = next_vertex;
+        vertices.push(vertex);
+    }
+    let x_values: Vec<_> = vertices.iter().map(|Coord { x, .. }| x).collect();
+    let y_values: Vec<_> = vertices.iter().map(|Coord { y, .. }| y).collect();
+    let area = tie_shoelace(&x_values, &y_values).abs_diff(tie_shoelace(&y_values, &x_values)) / 2;
+    return area + perimeter / 2 + 1;
+}
+
+/// The real trench hidden in a colour: five hex digits of distance, then one digit of direction.
+pub fn hex_to_path(hex: &str) -> Coord {
+    let distance = i64::from_str_radix(&hex[0..5], 16).unwrap();
+    let direction = [Direction::RIGHT, Direction::DOWN, Direction::LEFT, Direction::UP][hex.chars().last().unwrap().to_string().parse::<usize>().unwrap()];
+    return direction * distance;
+}
+
+fn tie_shoelace(x_values: &[&i64], y_values: &[&i64]) -> i64 {
+    x_values[0..x_values.len() - 1].iter().zip(y_values[1..y_values.len()].iter()).map(|(&&x, &&y)| x * y).sum::<i64>()
+}
+
+fn parse(line: &mut Line) -> Result<Instruction, ParseError> {
+    let directions = [("R", Direction::RIGHT), ("L", Direction::LEFT), ("U", Direction::UP), ("D", Direction::DOWN)];
+    let direction = line.one_of(&directions, "one of `R`, `L`, `U` or `D`")?;
+    let scalar: i64 = line.integer("a distance")?;
+    let colour_expected = "a colour like `(#70c710)`";
+    let hex = line.between("(#", ")", |line| line.take_while(|c| c.is_ascii_hexdigit(), colour_expected))?;
+    if hex.len() != 6 || !hex.ends_with(['0', '1', '2', '3']) {
+        return Err(line.error(hex, colour_expected));
+    }
+    return Ok(Instruction { path: direction * scalar, hex: hex.to_owned() });
+}
@@ -0,0 +1,172 @@
+//! The lagoon dug out cube by cube, to check the shoelace formula against.
+
+use std::collections::{HashSet, VecDeque};
+use common::{Coord, Direction};
+
+/// Digs out the trench one cube at a time, then floods the outside of it and counts everything else.
+pub fn lagoon_size(paths: impl Iterator<Item=Coord>) -> u64 {
+    let mut position = Coord::ORIGIN;
+    let mut trench = HashSet::from([position]);
+    for path in paths {
+        let direction = Coord::new(path.x.signum(), path.y.signum());
+        for _ in 0..path.x.abs() + path.y.abs() {
+            position += direction;
+            trench.insert(position);
+        }
+    }
+
+    // One cube of margin all around, so the outside is connected
+    let min = Coord::new(trench.iter().map(|c| c.x).min().unwrap() - 1, trench.iter().map(|c| c.y).min().unwrap() - 1);
+    let max = Coord::new(trench.iter().map(|c| c.x).max().unwrap() + 1, trench.iter().map(|c| c.y).max().unwrap() + 1);
+    let mut outside = HashSet::from([min]);
+    let mut queue = VecDeque::from([min]);
+    while let Some(coord) = queue.pop_front() {
+        for direction in Direction::ALL {
+            let next = coord + direction;
+            if (min.x..=max.x).contains(&next.x) && (min.y..=max.y).contains(&next.y)
+                && !trench.contains(&next) && outside.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let area = (max.x - min.x + 1) * (max.y - min.y + 1);
+    return area as u64 - outside.len() as u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use proptest::prelude::*;
+    use super::*;
+
+    const SIDE: i64 = 8;
+    /// The corners of a unit square, from its top left one, in row order.
+    const CORNERS: [Coord; 4] = [Coord::new(0, 0), Coord::new(1, 0), Coord::new(0, 1), Coord::new(1, 1)];
+
+    /// The outline of a region of unit squares, each named by its top left corner. The region grows from the square
+    /// at the origin, each of `growth` picking a square and adding its neighbour in a direction, if that stays inside
+    /// a `SIDE` by `SIDE` area.
+    ///
+    /// Holes, pockets of the outside that the trench would seal in, and squares where the region would only touch
+    /// itself at a corner all get filled in, so that the outline never touches itself and can be dug as a trench. It
+    /// is dug clockwise, or anticlockwise if `reversed`.
+    fn outline(growth: &[(usize, Direction)], reversed: bool) -> Vec<Coord> {
+        let mut region = HashSet::from([Coord::ORIGIN]);
+        let mut grown = vec![Coord::ORIGIN];
+        for (pick, direction) in growth {
+            let square = grown[pick % grown.len()] + *direction;
+            if square.is_within(SIDE as usize, SIDE as usize) && region.insert(square) {
+                grown.push(square);
+            }
+        }
+
+        loop {
+            // Whatever the outside cannot reach from around the area is a hole
+            let mut outside = HashSet::from([Coord::new(-1, -1)]);
+            let mut queue = VecDeque::from([Coord::new(-1, -1)]);
+            while let Some(square) = queue.pop_front() {
+                for direction in Direction::ALL {
+                    let next = square + direction;
+                    if (-1..=SIDE).contains(&next.x) && (-1..=SIDE).contains(&next.y)
+                        && !region.contains(&next) && outside.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            for x in 0..SIDE {
+                for y in 0..SIDE {
+                    if !outside.contains(&Coord::new(x, y)) {
+                        region.insert(Coord::new(x, y));
+                    }
+                }
+            }
+
+            // A lattice point off the trench that only leads outside between two trench cubes side by side is
+            // outside the loop, but sealed in once the trench is dug, so its pocket gets filled in too
+            let off_trench = |point: Coord| CORNERS.iter().all(|offset| !region.contains(&(point - *offset)));
+            let mut outside = HashSet::from([Coord::new(-1, -1)]);
+            let mut queue = VecDeque::from([Coord::new(-1, -1)]);
+            while let Some(point) = queue.pop_front() {
+                for direction in Direction::ALL {
+                    let next = point + direction;
+                    if (-1..=SIDE + 1).contains(&next.x) && (-1..=SIDE + 1).contains(&next.y)
+                        && off_trench(next) && outside.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            let sealed = (0..=SIDE).flat_map(|x| (0..=SIDE).map(move |y| Coord::new(x, y)))
+                .find(|point| off_trench(*point) && !outside.contains(point));
+            if let Some(point) = sealed {
+                region.extend(CORNERS.map(|offset| point - offset));
+                continue;
+            }
+
+            let pinch = (0..SIDE - 1).flat_map(|x| (0..SIDE - 1).map(move |y| Coord::new(x, y))).find(|corner| {
+                let [a, b, c, d] = CORNERS.map(|offset| region.contains(&(*corner + offset)));
+                (a && d && !b && !c) || (b && c && !a && !d)
+            });
+            match pinch {
+                Some(corner) => region.insert(corner + Coord::new(1, 0)),
+                None => break,
+            };
+        }
+
+        // Each side of the region's squares facing outside, leading from one lattice point to the next clockwise
+        let mut edges = HashMap::new();
+        for square in &region {
+            let [top_left, top_right, bottom_left, bottom_right] = CORNERS.map(|offset| *square + offset);
+            for (direction, from, to) in [
+                (Direction::UP, top_left, top_right),
+                (Direction::RIGHT, top_right, bottom_right),
+                (Direction::DOWN, bottom_right, bottom_left),
+                (Direction::LEFT, bottom_left, top_left),
+            ] {
+                if !region.contains(&(*square + direction)) {
+                    edges.insert(from, to);
+                }
+            }
+        }
+
+        // The topmost of the leftmost points is a corner, so no path runs through it
+        let start = *edges.keys().min_by_key(|point| (point.y, point.x)).unwrap();
+        let mut paths: Vec<Coord> = vec![];
+        let mut point = start;
+        loop {
+            let next = edges[&point];
+            let step = next - point;
+            match paths.last_mut() {
+                Some(path) if Coord::new(path.x.signum(), path.y.signum()) == step => *path += step,
+                _ => paths.push(step),
+            }
+            point = next;
+            if point == start {
+                break;
+            }
+        }
+        if reversed {
+            paths = paths.into_iter().rev().map(|path| -path).collect();
+        }
+        return paths;
+    }
+
+    fn trench() -> impl Strategy<Value=Vec<Coord>> {
+        let direction = prop::sample::select(Direction::ALL.to_vec());
+        return (prop::collection::vec((any::<usize>(), direction), 0..80), any::<bool>())
+            .prop_map(|(growth, reversed)| outline(&growth, reversed));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_flood_fill(paths in trench()) {
+            prop_assert_eq!(crate::lagoon_size(paths.iter().copied()), lagoon_size(paths.iter().copied()));
+        }
+    }
+
+    #[test]
+    fn fills_the_example() {
+        let paths = [(6, 0), (0, 5), (-2, 0), (0, 2), (2, 0), (0, 2), (-5, 0), (0, -2), (-1, 0), (0, -2), (2, 0), (0, -3), (-2, 0), (0, -2)];
+        assert_eq!(lagoon_size(paths.into_iter().map(|(x, y)| Coord::new(x, y))), 62);
+    }
+}
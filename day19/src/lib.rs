@@ -0,0 +1,307 @@
+//! Day 19: Aplenty
+
+use std::borrow::ToOwned;
+use std::collections::{HashMap, HashSet};
+use common::{Line, Lines, ParseError, Solution, SolveError};
+
+/// The solver for day 19.
+pub struct Day19;
+
+/// The workflows and the parts to sort with them.
+pub struct System {
+    /// Every workflow by label, including the empty `A` and `R` workflows that end a part's journey.
+    pub workflows: HashMap<String, Workflow>,
+    /// Each part's `x`, `m`, `a` and `s` ratings.
+    pub parts: Vec<HashMap<String, i64>>,
+}
+
+#[derive(Debug)]
+/// A list of rules a part goes through in order.
+pub struct Workflow {
+    /// The name other workflows send parts to.
+    pub label: String,
+    /// The rules, in the order they are tried.
+    pub rules: Vec<Rule>,
+    /// Where parts go when none of the rules match.
+    pub else_label: String,
+}
+
+/// A rule like `a<2006:qkq`, sending parts whose `category` is below or above `value` to `dest_label`.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    /// The rating compared, one of `x`, `m`, `a` and `s`.
+    pub category: String,
+    /// The comparison, `<` or `>`.
+    pub op: char,
+    /// The number the rating is compared with.
+    pub value: i64,
+    /// Where matching parts go.
+    pub dest_label: String,
+}
+
+#[derive(Debug, Clone)]
+struct Range {
+    lower_limit: i64,
+    upper_limit: i64,
+}
+
+impl Range {
+    fn valid_value_count(&self) -> i64 {
+        return (self.upper_limit - self.lower_limit).max(0);
+    }
+}
+
+impl Rule {
+    /// The workflow this rule sends the part to, `None` if the part does not match.
+    pub fn matches(&self, part: &HashMap<String, i64>) -> Option<String> {
+        let matches = match self.op {
+            '>' => part[&self.category] > self.value,
+            '<' => part[&self.category] < self.value,
+            _ => panic!("Invalid op")
+        };
+
+        if matches {
+            Some(self.dest_label.to_owned())
+        } else {
+            None
+        }
+    }
+}
+
+impl Workflow {
+    /// Reads a workflow whose label is not one of `workflows` yet, along with every label it sends parts to.
+    fn new<'a>(line: &mut Line<'a>, workflows: &HashMap<String, Workflow>) -> Result<(Workflow, Vec<&'a str>), ParseError> {
+        let label = line.name("a workflow like `px{a<2006:qkq,m>2090:A,rfg}`")?;
+        if workflows.contains_key(label) {
+            return Err(line.error(label, "a workflow label not used before"));
+        }
+        let items = line.between("{", "}", |line| line.list(",", |line| {
+            let name = line.name("a rule like `a<2006:qkq` or a workflow label")?;
+            let Some(op) = ["<", ">"].into_iter().find(|op| line.optional(op)) else {
+                return Ok((name, None));
+            };
+            let value = line.integer("a rating")?;
+            line.literal(":")?;
+            let dest_label = line.name("a workflow label")?;
+            let rule = Rule { category: name.to_owned(), op: op.chars().next().unwrap(), value, dest_label: dest_label.to_owned() };
+            return Ok((name, Some((rule, dest_label))));
+        }))?;
+
+        let ((else_label, last_rule), rule_items) = items.split_last().unwrap();
+        if last_rule.is_some() {
+            return Err(line.error(else_label, "a workflow label after the rules"));
+        }
+        let mut rules = vec![];
+        let mut destinations = vec![];
+        for (name, rule) in rule_items {
+            match rule {
+                Some((rule, dest_label)) if ["x", "m", "a", "s"].contains(name) => {
+                    rules.push(rule.clone());
+                    destinations.push(*dest_label);
+                }
+                _ => return Err(line.error(name, "a rule like `a<2006:qkq`")),
+            }
+        }
+        destinations.push(else_label);
+
+        return Ok((Workflow { label: label.to_owned(), rules, else_label: else_label.to_string() }, destinations));
+    }
+
+    /// The label of the workflow this one sends `part` to.
+    pub fn transition(&self, part: &HashMap<String, i64>) -> String {
+        for rule in &self.rules {
+            if let Some(workflow) = rule.matches(part) {
+                return workflow;
+            }
+        }
+        return self.else_label.to_owned();
+    }
+}
+
+const ACCEPT: &str = "A";
+const REJECT: &str = "R";
+const START: &str = "in";
+
+/// Whether `part` ends up accepted after starting at the `in` workflow, or an error if the workflows send it
+/// round in a loop.
+pub fn is_accepted(workflows: &HashMap<String, Workflow>, part: &HashMap<String, i64>) -> Result<bool, SolveError> {
+    let mut visited = HashSet::new();
+    let mut current_workflow = &workflows[START];
+    while current_workflow.label != ACCEPT && current_workflow.label != REJECT {
+        if !visited.insert(&current_workflow.label) {
+            return Err(endless_loop(&current_workflow.label));
+        }
+        current_workflow = &workflows[&current_workflow.transition(part)];
+    }
+    return Ok(current_workflow.label == ACCEPT);
+}
+
+/// How many parts with every rating from 1 to 4000 would be accepted, or an error if the workflows send some of
+/// them round in a loop.
+pub fn accepted_combinations(workflows: &HashMap<String, Workflow>) -> Result<i64, SolveError> {
+    let constraints = paths_to_acceptance(&workflows[START], workflows, HashMap::from([
+        ("x".to_owned(), Range { lower_limit: 1, upper_limit: 4001 }),
+        ("m".to_owned(), Range { lower_limit: 1, upper_limit: 4001 }),
+        ("a".to_owned(), Range { lower_limit: 1, upper_limit: 4001 }),
+        ("s".to_owned(), Range { lower_limit: 1, upper_limit: 4001 }),
+    ]), &mut vec![])?;
+
+    return Ok(constraints.iter().map(|p| p.values().fold(1, |acc, c| acc * c.valid_value_count())).sum::<i64>());
+}
+
+fn endless_loop(label: &str) -> SolveError {
+    return SolveError::new(&format!("the workflows send parts round a loop through `{label}`"));
+}
+
+/// The ranges of the parts `workflow` accepts out of those in `reject_part`. `path` holds the workflows that led
+/// to this one, so parts coming back to one of them are caught going round a loop.
+fn paths_to_acceptance<'a>(workflow: &'a Workflow, workflow_map: &'a HashMap<String, Workflow>, mut reject_part: HashMap<String, Range>,
+                           path: &mut Vec<&'a str>) -> Result<Vec<HashMap<String, Range>>, SolveError> {
+    let mut result = vec![];
+
+    if reject_part.values().any(|range| range.valid_value_count() == 0) || workflow.label == REJECT {
+        return Ok(vec![]);
+    } else if workflow.label == ACCEPT {
+        return Ok(vec![reject_part]);
+    } else if path.contains(&workflow.label.as_str()) {
+        return Err(endless_loop(&workflow.label));
+    }
+    path.push(&workflow.label);
+
+    for rule in &workflow.rules {
+        let mut accept_part = reject_part.clone();
+        let existing_constraint = &reject_part[&rule.category];
+        match rule.op {
+
+            '>' => {
+                accept_part.insert(rule.category.clone(), Range {
+                    lower_limit: existing_constraint.lower_limit.max(rule.value + 1),
+                    upper_limit: existing_constraint.upper_limit,
+                });
+                reject_part.insert(rule.category.clone(), Range {
+                    lower_limit: existing_constraint.lower_limit,
+                    upper_limit: existing_constraint.upper_limit.min(rule.value + 1),
+                })
+            },
+            '<' => {
+                accept_part.insert(rule.category.clone(), Range {
+                    lower_limit: existing_constraint.lower_limit,
+                    upper_limit: existing_constraint.upper_limit.min(rule.value),
+                });
+                reject_part.insert(rule.category.clone(), Range {
+                    lower_limit: existing_constraint.lower_limit.max(rule.value),
+                    upper_limit: existing_constraint.upper_limit,
+                })
+            },
+            _ => panic!("Invalid op")
+        };
+
+        result.extend(paths_to_acceptance(&workflow_map[&rule.dest_label], workflow_map, accept_part, path)?);
+    }
+
+    result.extend(paths_to_acceptance(&workflow_map[&workflow.else_label], workflow_map, reject_part.clone(), path)?);
+    path.pop();
+
+    return Ok(result);
+}
+
+fn new_part(line: &mut Line) -> Result<HashMap<String, i64>, ParseError> {
+    let part = line.between("{", "}", |line| line.map(",", "=", "a rating category", |line| line.integer("a rating")))?;
+    if part.len() != 4 || !["x", "m", "a", "s"].iter().all(|category| part.contains_key(*category)) {
+        return Err(line.error(line.text, "a part like `{x=787,m=2655,a=1222,s=2876}`"));
+    }
+    return Ok(part);
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = System;
+
+    fn parse(input: &str) -> Result<System, ParseError> {
+        let [mut workflow_block, mut part_block] = Lines::new(input)
+            .split_blocks(["a workflow", "a blank line before the parts"])?;
+        let mut workflows: HashMap<String, Workflow> = HashMap::new();
+        for label in [ACCEPT, REJECT] {
+            workflows.insert(label.to_owned(), Workflow {
+                label: label.to_owned(),
+                rules: vec![],
+                else_label: "".to_string(),
+            });
+        }
+        let mut destinations = vec![];
+        workflow_block.each(|line| {
+            let (workflow, labels) = Workflow::new(line, &workflows)?;
+            destinations.extend(labels.into_iter().map(|label| (*line, label)));
+            workflows.insert(workflow.label.to_owned(), workflow);
+            return Ok(());
+        })?;
+
+        for (line, label) in destinations {
+            if !workflows.contains_key(label) {
+                return Err(line.error(label, "the label of a workflow"));
+            }
+        }
+        if !workflows.contains_key(START) {
+            return Err(ParseError::end_of_input(workflow_block.line_number() - 1, "a workflow named `in`"));
+        }
+
+        let parts = part_block.each(new_part)?;
+        return Ok(System { workflows, parts });
+    }
+
+    fn part1(system: &System) -> Result<String, SolveError> {
+        let System { workflows, parts } = system;
+        let mut total = 0;
+        for part in parts {
+            if is_accepted(workflows, part)? {
+                total += part.values().sum::<i64>();
+            }
+        }
+        return Ok(total.to_string());
+    }
+
+    fn part2(system: &System) -> Result<String, SolveError> {
+        return accepted_combinations(&system.workflows).map(|combinations| combinations.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn narrows_ranges_already_narrowed() {
+        // No `x` is both above 1000 and below 500
+        let system = Day19::parse("in{x>1000:aa,R}\naa{x<500:A,R}\n\n{x=1,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(Day19::part2(&system), Ok("0".to_string()));
+
+        // Below 2000 and then below 3000 is just below 2000
+        let system = Day19::parse("in{x<2000:aa,R}\naa{x<3000:A,R}\n\n{x=1,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(Day19::part2(&system), Ok((1999 * 4000u64.pow(3)).to_string()));
+    }
+
+    #[test]
+    fn rejects_labels_of_workflows_not_defined() {
+        let error = Day19::parse("in{x>10:px,R}\n\n{x=1,m=1,a=1,s=1}\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.found), (1, 9, Found::Token("px".to_string())));
+        let error = Day19::parse("in{x>10:A,qq}\n\n{x=1,m=1,a=1,s=1}\n").err().unwrap();
+        assert_eq!((error.column, error.expected.as_str()), (11, "the label of a workflow"));
+        let error = Day19::parse("in{x>10:A,R}\nin{R}\n\n{x=1,m=1,a=1,s=1}\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 1, "a workflow label not used before"));
+    }
+
+    #[test]
+    fn reports_workflows_sending_parts_round_a_loop() {
+        let system = Day19::parse("in{x>10:in,R}\n\n{x=1,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(Day19::part1(&system), Ok("0".to_string()));
+        let error = Err(SolveError::new("the workflows send parts round a loop through `in`"));
+        assert_eq!(Day19::part2(&system), error);
+        let system = Day19::parse("in{x>10:in,R}\n\n{x=11,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(Day19::part1(&system), error);
+
+        // `in` only sends parts with an `x` above 10 to `aa`, so none of them go back round
+        let system = Day19::parse("in{x>10:aa,A}\naa{x<5:in,R}\n\n{x=11,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(Day19::part2(&system), Ok((10 * 4000u64.pow(3)).to_string()));
+    }
+}
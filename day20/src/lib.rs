@@ -0,0 +1,384 @@
+//! Day 20: Pulse Propagation
+//!
+//! The network is a map from module name to [`Node`], with high pulses as `true`.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Formatter};
+use gcd::Gcd;
+use common::{Cycle, Line, Lines, ParseError, Simulation, Solution, SolveError};
+use tracing::debug;
+use crate::ComponentType::{Broadcaster, Conjunction, FlipFlop};
+
+/// The solver for day 20.
+pub struct Day20;
+
+/// A module, with the state it is in between button presses.
+#[derive(Clone)]
+pub struct Node {
+    /// The module's name, without its `%` or `&` prefix.
+    pub name: String,
+    /// The modules this one sends its pulses to.
+    pub neighbors: Vec<String>,
+    /// What kind of module it is.
+    pub component_type: ComponentType,
+    /// For conjunctions, the last pulse received from each input.
+    pub memory: HashMap<String, bool>,
+    /// For flip-flops, whether they are on.
+    pub state: bool,
+}
+
+impl PartialEq<Self> for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.memory == other.memory && self.state == other.state
+    }
+}
+
+impl Debug for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Node")
+            .field("name", &self.name)
+            .field("state", if self.state { &0 } else { &1 })
+            .finish()
+    }
+}
+
+impl Eq for Node {}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// The kinds of module: `%`, `&` and `broadcaster`.
+pub enum ComponentType {
+    /// A `%` module, flipping on low pulses.
+    FlipFlop,
+    /// A `&` module, remembering its inputs.
+    Conjunction,
+    /// The `broadcaster`, repeating every pulse it gets.
+    Broadcaster,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+/// A pulse on its way from one module to another.
+pub struct Signal {
+    /// The module sending the pulse.
+    pub source: String,
+    /// The module receiving it.
+    pub target: String,
+    /// Whether the pulse is high.
+    pub pulse: bool,
+}
+
+
+impl Signal {
+    /// Delivers this pulse, updating its target, and returns the pulses the target sends on.
+    pub fn process(&self, graph: &mut HashMap<String, Node>) -> Vec<Signal> {
+        match graph.get_mut(&self.target) {
+            None => vec![],
+            Some(target) => {
+                let pulse = match target.component_type {
+                    Broadcaster => self.pulse,
+                    Conjunction => {
+                        target.memory.insert(self.source.to_owned(), self.pulse);
+                        target.memory.values().any(|&p| !p)
+                    }
+                    FlipFlop => {
+                        if !self.pulse {
+                            target.state = !target.state;
+                        }
+                        target.state
+                    }
+                };
+
+                if self.pulse && target.component_type == FlipFlop {
+                    return vec![];
+                }
+
+                return target.neighbors.iter()
+                    .map(|n| {
+                        Signal { source: self.target.to_owned(), target: n.to_owned(), pulse }
+                    })
+                    .collect();
+            }
+        }
+    }
+}
+
+fn parse(line: &mut Line, graph: &HashMap<String, Node>) -> Result<Node, ParseError> {
+    let module_expected = "a module like `%a -> b, c`";
+    let component_type = if line.optional("%") {
+        FlipFlop
+    } else if line.optional("&") {
+        Conjunction
+    } else {
+        Broadcaster
+    };
+    let name = line.name(module_expected)?;
+    if component_type == Broadcaster && name != "broadcaster" {
+        return Err(line.error(name, module_expected));
+    }
+    if graph.contains_key(name) {
+        return Err(line.error(name, "a module name not used before"));
+    }
+    line.literal("->")?;
+    let all_neighbors = line.list(",", |line| Ok(line.name("a destination module")?.to_owned()))?;
+    return Ok(Node {
+        name: name.to_owned(),
+        neighbors: all_neighbors,
+        component_type,
+        memory: HashMap::new(),
+        state: false,
+    });
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = HashMap<String, Node>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Node>, ParseError> {
+        let mut graph = HashMap::new();
+        let mut conjunction_nodes = HashMap::new();
+        Lines::new(input).each(|line| {
+            let node = parse(line, &graph)?;
+            if node.component_type == Conjunction {
+                conjunction_nodes.insert(node.name.to_owned(), vec![]);
+            }
+            graph.insert(node.name.to_owned(), node);
+            return Ok(());
+        })?;
+
+        for node in graph.values() {
+            for neighbor in &node.neighbors {
+                let node_inputs = conjunction_nodes.get_mut(neighbor);
+                if let Some(v) = node_inputs {
+                    v.push(node.name.to_owned());
+                }
+            }
+        }
+
+        for (conjunction_node, inputs) in &conjunction_nodes {
+            let node = graph.get_mut(conjunction_node).unwrap();
+            for input in inputs {
+                node.memory.insert(input.to_owned(), false);
+            }
+        }
+
+        return Ok(graph);
+    }
+
+    fn part1(initial_graph: &HashMap<String, Node>) -> Result<String, SolveError> {
+        let mut graph = initial_graph.clone();
+
+        let mut low_count = 0;
+        let mut high_count = 0;
+
+        for _ in 0..1000 {
+            let signals = press_button(&mut graph);
+            let high_pulses = signals.iter().filter(|s| s.pulse).count();
+            high_count += high_pulses;
+            low_count += signals.len() - high_pulses;
+        }
+
+        return Ok((low_count * high_count).to_string());
+    }
+
+    fn part2(initial_graph: &HashMap<String, Node>) -> Result<String, SolveError> {
+        // rx is fed by a single conjunction, which only sends it a low pulse once every one of its inputs
+        // has sent it a high pulse during the same button press. Each input is driven by its own part
+        // of the network, so the press count for each can be found on its own.
+        let hubs: Vec<&Node> = initial_graph.values()
+            .filter(|node| node.neighbors.iter().any(|n| n == "rx"))
+            .collect();
+        let hub = match hubs[..] {
+            [] => return Err(SolveError::new("no module sends pulses to rx")),
+            [hub] if hub.component_type == Conjunction && !hub.memory.is_empty() => hub,
+            _ => return Err(SolveError::new("rx is not fed by a single conjunction")),
+        };
+
+        let mut presses = 1;
+        for feeder in hub.memory.keys() {
+            presses = lcm(presses, high_pulse_period(initial_graph, feeder)?)
+                .ok_or_else(|| SolveError::new("rx gets its low pulse after more presses than can be counted"))?;
+        }
+        return Ok(presses.to_string());
+    }
+
+    fn simulate(graph: &HashMap<String, Node>) -> Option<Box<dyn Simulation>> {
+        return Some(Box::new(Pulses::new(graph)));
+    }
+}
+
+/// Button presses played out one pulse at a time.
+pub struct Pulses {
+    /// Every module by name.
+    pub graph: HashMap<String, Node>,
+    /// The pulses sent but not yet delivered.
+    pub queue: VecDeque<Signal>,
+    /// How many times the button has been pressed.
+    pub presses: usize,
+    /// The pulse delivered by the last step.
+    pub last: Option<Signal>,
+    /// How many low pulses have been sent.
+    pub low_count: usize,
+    /// How many high pulses have been sent.
+    pub high_count: usize,
+}
+
+impl Pulses {
+    /// A network in the state given, with the button not yet pressed.
+    pub fn new(graph: &HashMap<String, Node>) -> Pulses {
+        return Pulses { graph: graph.clone(), queue: VecDeque::new(), presses: 0, last: None, low_count: 0, high_count: 0 };
+    }
+}
+
+/// How many modules are shown side by side.
+const FRAME_COLUMNS: usize = 6;
+
+impl Simulation for Pulses {
+    fn step(&mut self) -> bool {
+        if self.queue.is_empty() {
+            self.queue.push_back(button_signal());
+            self.presses += 1;
+        }
+        let signal = self.queue.pop_front().unwrap();
+        self.queue.extend(signal.process(&mut self.graph));
+        if signal.pulse {
+            self.high_count += 1;
+        } else {
+            self.low_count += 1;
+        }
+        self.last = Some(signal);
+        return true;
+    }
+
+    fn frame(&self) -> String {
+        let mut names: Vec<&String> = self.graph.keys().collect();
+        names.sort();
+        let cells: Vec<String> = names.iter()
+            .map(|name| {
+                let node = &self.graph[*name];
+                let state = match node.component_type {
+                    FlipFlop => (if node.state { "on" } else { "off" }).to_string(),
+                    Conjunction => format!("{}/{}", node.memory.values().filter(|p| **p).count(), node.memory.len()),
+                    Broadcaster => String::new(),
+                };
+                let sign = match node.component_type {
+                    FlipFlop => "%",
+                    Conjunction => "&",
+                    Broadcaster => "",
+                };
+                let marker = if self.last.as_ref().is_some_and(|s| &s.target == *name) { '*' } else { ' ' };
+                format!("{marker}{sign}{name} {state}")
+            })
+            .collect();
+        let width = cells.iter().map(|cell| cell.len()).max().unwrap_or(0) + 2;
+        let mut frame = String::new();
+        for row in cells.chunks(FRAME_COLUMNS) {
+            frame.push_str(row.iter().map(|cell| format!("{cell:width$}")).collect::<String>().trim_end());
+            frame.push('\n');
+        }
+        return frame;
+    }
+
+    fn status(&self) -> String {
+        let last = match &self.last {
+            Some(signal) => format!("{} -{}-> {}", signal.source, if signal.pulse { "high" } else { "low" }, signal.target),
+            None => "nothing sent yet".to_string(),
+        };
+        return format!("Press {}: {last}, {} low and {} high pulses so far", self.presses, self.low_count, self.high_count);
+    }
+}
+
+/// Sends one low pulse to the broadcaster and returns every signal sent until the network settles.
+pub fn press_button(graph: &mut HashMap<String, Node>) -> Vec<Signal> {
+    let mut signal_queue = VecDeque::from([button_signal()]);
+    let mut signals = vec![];
+    while let Some(signal) = signal_queue.pop_front() {
+        signal_queue.extend(signal.process(graph));
+        signals.push(signal);
+    }
+    return signals;
+}
+
+/// How many presses apart `feeder` sends a high pulse, which it must do once at the end of each cycle.
+fn high_pulse_period(graph: &HashMap<String, Node>, feeder: &str) -> Result<u64, SolveError> {
+    let subgraph = upstream(graph, feeder);
+    let cycle = Cycle::find(&subgraph, |graph| {
+        let mut graph = graph.clone();
+        press_button(&mut graph);
+        graph
+    });
+
+    let mut graph = subgraph.clone();
+    let high_presses: Vec<usize> = (1..=cycle.start + cycle.period)
+        .filter(|_| press_button(&mut graph).iter().any(|s| s.source == feeder && s.pulse))
+        .collect();
+    if high_presses != [cycle.period] {
+        return Err(SolveError::new(&format!("{feeder} does not send one high pulse at the end of each cycle")));
+    }
+    debug!(feeder, modules = subgraph.len(), period = cycle.period, "feeder period");
+
+    return Ok(cycle.period as u64);
+}
+
+/// The part of the network that can influence `node`, which evolves independently of the rest.
+fn upstream(graph: &HashMap<String, Node>, node: &str) -> HashMap<String, Node> {
+    let mut names = HashSet::from([node.to_owned()]);
+    let mut queue = VecDeque::from([node.to_owned()]);
+    while let Some(name) = queue.pop_front() {
+        for source in graph.values().filter(|n| n.neighbors.contains(&name)) {
+            if names.insert(source.name.to_owned()) {
+                queue.push_back(source.name.to_owned());
+            }
+        }
+    }
+    return graph.iter()
+        .filter(|(name, _)| names.contains(*name))
+        .map(|(name, node)| (name.to_owned(), node.clone()))
+        .collect();
+}
+
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    return (a / a.gcd(b)).checked_mul(b);
+}
+
+fn button_signal() -> Signal {
+    return Signal {
+        source: "button".to_owned(),
+        target: "broadcaster".to_owned(),
+        pulse: false,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Found;
+    use super::*;
+
+    #[test]
+    fn rejects_modules_defined_twice() {
+        let error = Day20::parse("broadcaster -> a\n%a -> b\n&b -> a\n&a -> b\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (4, 2, Found::Token("a".to_string())));
+        assert_eq!(error.expected, "a module name not used before");
+    }
+
+    #[test]
+    fn lcm_reports_overflow() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(1 << 40, 1 << 50), Some(1 << 50));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn reports_networks_the_second_star_cannot_solve() {
+        let graph = Day20::parse("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a\n").unwrap();
+        assert_eq!(Day20::part2(&graph), Err(SolveError::new("no module sends pulses to rx")));
+
+        let graph = Day20::parse("broadcaster -> a\n%a -> rx\n").unwrap();
+        assert_eq!(Day20::part2(&graph), Err(SolveError::new("rx is not fed by a single conjunction")));
+
+        // b sends a high pulse at the end of every second press
+        let graph = Day20::parse("broadcaster -> a\n%a -> b\n&b -> hub\n&hub -> rx\n").unwrap();
+        assert_eq!(Day20::part2(&graph), Ok("2".to_string()));
+        // a sends its high pulse on the first of every two presses, so the periods cannot just be combined
+        let graph = Day20::parse("broadcaster -> a\n%a -> hub\n&hub -> rx\n").unwrap();
+        assert_eq!(Day20::part2(&graph), Err(SolveError::new("a does not send one high pulse at the end of each cycle")));
+    }
+}